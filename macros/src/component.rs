@@ -41,11 +41,13 @@ struct Attrs {
 enum StorageTy {
     Table,
     SparseSet,
+    Tag,
 }
 
 // values for `storage` attribute
 const TABLE: &str = "Table";
 const SPARSE_SET: &str = "SparseSet";
+const TAG: &str = "Tag";
 
 fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     let meta_items = bevy_macro_utils::parse_attrs(ast, COMPONENT)?;
@@ -64,12 +66,13 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                 attrs.storage = match get_lit_str(STORAGE, &m.lit)?.value().as_str() {
                     TABLE => StorageTy::Table,
                     SPARSE_SET => StorageTy::SparseSet,
+                    TAG => StorageTy::Tag,
                     s => {
                         return Err(Error::new_spanned(
                             m.lit,
                             format!(
-                                "Invalid storage type `{}`, expected '{}' or '{}'.",
-                                s, TABLE, SPARSE_SET
+                                "Invalid storage type `{}`, expected '{}', '{}' or '{}'.",
+                                s, TABLE, SPARSE_SET, TAG
                             ),
                         ))
                     }
@@ -100,6 +103,7 @@ fn storage_path(bevy_ecs_path: Path, ty: StorageTy) -> TokenStream2 {
     let typename = match ty {
         StorageTy::Table => Ident::new("TableStorage", Span::call_site()),
         StorageTy::SparseSet => Ident::new("SparseStorage", Span::call_site()),
+        StorageTy::Tag => Ident::new("TagStorage", Span::call_site()),
     };
 
     quote! { #bevy_ecs_path::component::#typename }
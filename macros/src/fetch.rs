@@ -0,0 +1,311 @@
+use bevy_macro_utils::Symbol;
+use proc_macro::TokenStream;
+use proc_macro2::{Group, Ident, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Data, DataStruct, DeriveInput, Error, Fields, GenericParam,
+    Generics, Result, Type,
+};
+
+pub const WORLD_QUERY: Symbol = Symbol("world_query");
+pub const MUTABLE: Symbol = Symbol("mutable");
+
+pub fn derive_world_query(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    match derive_world_query_impl(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+fn derive_world_query_impl(ast: &DeriveInput) -> Result<TokenStream2> {
+    let ecs_path = crate::bevy_ecs_path();
+    let is_mutable = parse_world_query_attr(ast)?;
+
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new_spanned(
+                &ast.ident,
+                "WorldQuery can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let vis = &ast.vis;
+    let struct_name = &ast.ident;
+    let item_name = format_ident!("{}Item", struct_name);
+    let fetch_name = format_ident!("{}Fetch", struct_name);
+    let state_name = format_ident!("{}State", struct_name);
+    let read_only_item_name = format_ident!("{}ReadOnlyItem", struct_name);
+    let read_only_fetch_name = format_ident!("{}ReadOnlyFetch", struct_name);
+
+    let field_idents = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let field_visibilities = fields.iter().map(|field| &field.vis).collect::<Vec<_>>();
+    // The lifetimes of the struct only exist to spell out field types such as `&'w T`. Any
+    // lifetime works for those, so the generated types use `'static` and drop the lifetimes.
+    let query_types = fields
+        .iter()
+        .map(|field| syn::parse2::<Type>(replace_lifetimes(field.ty.to_token_stream())))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut generics = ast.generics.clone();
+    generics.params = generics
+        .params
+        .into_iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+        .collect();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut item_generics = generics.clone();
+    item_generics.params.insert(0, parse_quote!('s));
+    item_generics.params.insert(0, parse_quote!('w));
+    let (item_impl_generics, item_ty_generics, _) = item_generics.split_for_impl();
+
+    let fetch_impl = |fetch_name: &Ident, item_name: &Ident, fetch_type: Ident| {
+        let field_fetch_types = query_types
+            .iter()
+            .map(|ty| quote! { <#ty as #ecs_path::query::WorldQuery>::#fetch_type })
+            .collect::<Vec<_>>();
+        quote! {
+            #vis struct #item_name #item_impl_generics #where_clause {
+                #(#field_visibilities #field_idents: <#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::Item,)*
+            }
+
+            #[doc(hidden)]
+            #vis struct #fetch_name #impl_generics #where_clause {
+                #(#field_idents: #field_fetch_types,)*
+            }
+
+            impl #item_impl_generics #ecs_path::query::Fetch<'w, 's> for #fetch_name #ty_generics #where_clause {
+                type Item = #item_name #item_ty_generics;
+                type State = #state_name #ty_generics;
+
+                unsafe fn init(
+                    world: &#ecs_path::world::World,
+                    state: &Self::State,
+                    last_change_tick: #ecs_path::component::Tick,
+                    change_tick: #ecs_path::component::Tick,
+                ) -> Self {
+                    #fetch_name {
+                        #(#field_idents: <#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::init(
+                            world,
+                            &state.#field_idents,
+                            last_change_tick,
+                            change_tick,
+                        ),)*
+                    }
+                }
+
+                const IS_DENSE: bool = true #(&& <#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::IS_DENSE)*;
+
+                #[inline]
+                unsafe fn set_archetype(
+                    &mut self,
+                    state: &Self::State,
+                    archetype: &#ecs_path::archetype::Archetype,
+                    tables: &#ecs_path::storage::Tables,
+                ) {
+                    #(<#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::set_archetype(
+                        &mut self.#field_idents,
+                        &state.#field_idents,
+                        archetype,
+                        tables,
+                    );)*
+                }
+
+                #[inline]
+                unsafe fn set_table(&mut self, state: &Self::State, table: &#ecs_path::storage::Table) {
+                    #(<#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::set_table(
+                        &mut self.#field_idents,
+                        &state.#field_idents,
+                        table,
+                    );)*
+                }
+
+                #[inline]
+                unsafe fn archetype_fetch(&mut self, archetype_index: usize) -> Self::Item {
+                    #item_name {
+                        #(#field_idents: <#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::archetype_fetch(
+                            &mut self.#field_idents,
+                            archetype_index,
+                        ),)*
+                    }
+                }
+
+                #[inline]
+                unsafe fn table_fetch(&mut self, table_row: usize) -> Self::Item {
+                    #item_name {
+                        #(#field_idents: <#field_fetch_types as #ecs_path::query::Fetch<'w, 's>>::table_fetch(
+                            &mut self.#field_idents,
+                            table_row,
+                        ),)*
+                    }
+                }
+            }
+        }
+    };
+
+    let fetch = fetch_impl(&fetch_name, &item_name, format_ident!("Fetch"));
+    let (read_only_fetch, read_only_fetch_name) = if is_mutable {
+        let read_only_fetch = fetch_impl(
+            &read_only_fetch_name,
+            &read_only_item_name,
+            format_ident!("ReadOnlyFetch"),
+        );
+        (
+            quote! {
+                #read_only_fetch
+
+                // SAFE: every field is fetched with its `WorldQuery::ReadOnlyFetch`
+                unsafe impl #impl_generics #ecs_path::query::ReadOnlyFetch for #read_only_fetch_name #ty_generics #where_clause {}
+            },
+            read_only_fetch_name,
+        )
+    } else {
+        let mut read_only_generics: Generics = generics.clone();
+        let read_only_where_clause = read_only_generics.make_where_clause();
+        for ty in query_types.iter() {
+            read_only_where_clause.predicates.push(parse_quote! {
+                <#ty as #ecs_path::query::WorldQuery>::Fetch: #ecs_path::query::ReadOnlyFetch
+            });
+        }
+        let read_only_where_clause = &read_only_generics.where_clause;
+        (
+            quote! {
+                // SAFE: every field's fetch is read only. Add `#[world_query(mutable)]` to derive
+                // a query with mutable fields.
+                unsafe impl #impl_generics #ecs_path::query::ReadOnlyFetch for #fetch_name #ty_generics #read_only_where_clause {}
+            },
+            fetch_name.clone(),
+        )
+    };
+
+    let (user_impl_generics, user_ty_generics, user_where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        #fetch
+
+        #read_only_fetch
+
+        #[doc(hidden)]
+        #vis struct #state_name #impl_generics #where_clause {
+            #(#field_idents: <#query_types as #ecs_path::query::WorldQuery>::State,)*
+        }
+
+        // SAFE: the access of every field is added, and an archetype or table only matches if it
+        // matches every field
+        unsafe impl #impl_generics #ecs_path::query::FetchState for #state_name #ty_generics #where_clause {
+            fn init(world: &mut #ecs_path::world::World) -> Self {
+                #state_name {
+                    #(#field_idents: <<#query_types as #ecs_path::query::WorldQuery>::State as #ecs_path::query::FetchState>::init(world),)*
+                }
+            }
+
+            fn try_init(world: &#ecs_path::world::World) -> Option<Self> {
+                Some(#state_name {
+                    #(#field_idents: <<#query_types as #ecs_path::query::WorldQuery>::State as #ecs_path::query::FetchState>::try_init(world)?,)*
+                })
+            }
+
+            fn update_component_access(
+                &self,
+                access: &mut #ecs_path::query::FilteredAccess<#ecs_path::component::ComponentId>,
+            ) {
+                #(self.#field_idents.update_component_access(access);)*
+            }
+
+            fn update_archetype_component_access(
+                &self,
+                archetype: &#ecs_path::archetype::Archetype,
+                access: &mut #ecs_path::query::Access<#ecs_path::archetype::ArchetypeComponentId>,
+            ) {
+                #(self.#field_idents.update_archetype_component_access(archetype, access);)*
+            }
+
+            fn matches_archetype(&self, archetype: &#ecs_path::archetype::Archetype) -> bool {
+                true #(&& self.#field_idents.matches_archetype(archetype))*
+            }
+
+            fn matches_table(&self, table: &#ecs_path::storage::Table) -> bool {
+                true #(&& self.#field_idents.matches_table(table))*
+            }
+        }
+
+        impl #user_impl_generics #ecs_path::query::WorldQuery for #struct_name #user_ty_generics #user_where_clause {
+            type Fetch = #fetch_name #ty_generics;
+            type State = #state_name #ty_generics;
+            type ReadOnlyFetch = #read_only_fetch_name #ty_generics;
+        }
+
+        // The struct itself is never constructed, only its item types are.
+        const _: () = {
+            #[allow(dead_code)]
+            fn read_fields #user_impl_generics (query: &#struct_name #user_ty_generics) #user_where_clause {
+                #(let _ = &query.#field_idents;)*
+            }
+        };
+    })
+}
+
+fn parse_world_query_attr(ast: &DeriveInput) -> Result<bool> {
+    let meta_items = bevy_macro_utils::parse_attrs(ast, WORLD_QUERY)?;
+
+    let mut is_mutable = false;
+    for meta in meta_items {
+        use syn::{
+            Meta::Path as MetaPath,
+            NestedMeta::{Lit, Meta},
+        };
+        match meta {
+            Meta(MetaPath(path)) if path == MUTABLE => is_mutable = true,
+            Meta(meta_item) => {
+                return Err(Error::new_spanned(
+                    meta_item.path(),
+                    format!(
+                        "unknown world_query attribute `{}`",
+                        meta_item.path().into_token_stream()
+                    ),
+                ));
+            }
+            Lit(lit) => {
+                return Err(Error::new_spanned(
+                    lit,
+                    "unexpected literal in world_query attribute",
+                ))
+            }
+        }
+    }
+
+    Ok(is_mutable)
+}
+
+/// Replaces every lifetime in `tokens` with `'static`.
+fn replace_lifetimes(tokens: TokenStream2) -> TokenStream2 {
+    let mut output = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                output.push(TokenTree::Punct(punct));
+                if let Some(TokenTree::Ident(_)) = tokens.peek() {
+                    tokens.next();
+                    output.push(TokenTree::Ident(Ident::new("static", Span::call_site())));
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), replace_lifetimes(group.stream()));
+                replaced.set_span(group.span());
+                output.push(TokenTree::Group(replaced));
+            }
+            token => output.push(token),
+        }
+    }
+    output.into_iter().collect()
+}
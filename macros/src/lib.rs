@@ -1,6 +1,7 @@
 extern crate proc_macro;
 
 mod component;
+mod fetch;
 
 use bevy_macro_utils::{derive_label, BevyManifest};
 use proc_macro::TokenStream;
@@ -228,19 +229,17 @@ pub fn impl_query_set(_input: TokenStream) -> TokenStream {
                 fn init(world: &mut World, system_meta: &mut SystemMeta, config: Self::Config) -> Self {
                     #(
                         let mut #query = QueryState::<#query, #filter>::new(world);
-                        assert_component_access_compatibility(
-                            &system_meta.name,
-                            std::any::type_name::<#query>(),
-                            std::any::type_name::<#filter>(),
-                            &system_meta.component_access_set,
+                        system_meta.check_component_access(
+                            &query_param_name::<#query, #filter>(),
                             &#query.component_access,
                             world,
                         );
                     )*
                     #(
-                        system_meta
-                            .component_access_set
-                            .add(#query.component_access.clone());
+                        system_meta.add_component_access(
+                            &query_param_name::<#query, #filter>(),
+                            #query.component_access.clone(),
+                        );
                         system_meta
                             .archetype_component_access
                             .extend(&#query.archetype_component_access);
@@ -271,8 +270,12 @@ pub fn impl_query_set(_input: TokenStream) -> TokenStream {
                     state: &'s mut Self,
                     system_meta: &SystemMeta,
                     world: &'w World,
-                    change_tick: u32,
+                    change_tick: Tick,
                 ) -> Self::Item {
+                    let (#(#query,)*) = &mut state.0;
+                    #(
+                        #query.remove_pruned_archetypes(world.archetypes());
+                    )*
                     QuerySet {
                         query_states: &state.0,
                         world,
@@ -415,7 +418,7 @@ pub fn derive_system_param(input: TokenStream) -> TokenStream {
                 state: &'s mut Self,
                 system_meta: &#path::system::SystemMeta,
                 world: &'w #path::world::World,
-                change_tick: u32,
+                change_tick: #path::component::Tick,
             ) -> Self::Item {
                 #struct_name {
                     #(#fields: <<#field_types as #path::system::SystemParam>::Fetch as #path::system::SystemParamFetch>::get_param(&mut state.state.#field_indices, system_meta, world, change_tick),)*
@@ -476,3 +479,8 @@ pub(crate) fn bevy_ecs_path() -> syn::Path {
 pub fn derive_component(input: TokenStream) -> TokenStream {
     component::derive_component(input)
 }
+
+#[proc_macro_derive(WorldQuery, attributes(world_query))]
+pub fn derive_world_query(input: TokenStream) -> TokenStream {
+    fetch::derive_world_query(input)
+}
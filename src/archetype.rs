@@ -6,6 +6,7 @@ use crate::{
     component::{ComponentId, StorageType},
     entity::{Entity, EntityLocation},
     storage::{Column, SparseArray, SparseSet, SparseSetIndex, TableId},
+    world::MemoryUsage,
 };
 use std::{
     borrow::Cow,
@@ -99,6 +100,16 @@ impl Edges {
         self.remove_bundle_intersection
             .insert(bundle_id, archetype_id);
     }
+
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        self.add_bundle
+            .values()
+            .map(|add_bundle| MemoryUsage::of_vec(&add_bundle.bundle_status))
+            .sum::<MemoryUsage>()
+            + self.add_bundle.memory_usage()
+            + self.remove_bundle.memory_usage()
+            + self.remove_bundle_intersection.memory_usage()
+    }
 }
 
 struct TableInfo {
@@ -123,21 +134,27 @@ pub struct Archetype {
     table_info: TableInfo,
     table_components: Cow<'static, [ComponentId]>,
     sparse_set_components: Cow<'static, [ComponentId]>,
+    tag_components: Cow<'static, [ComponentId]>,
     pub(crate) unique_components: SparseSet<ComponentId, Column>,
     pub(crate) components: SparseSet<ComponentId, ArchetypeComponentInfo>,
+    removed: bool,
 }
 
 impl Archetype {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: ArchetypeId,
         table_id: TableId,
         table_components: Cow<'static, [ComponentId]>,
         sparse_set_components: Cow<'static, [ComponentId]>,
+        tag_components: Cow<'static, [ComponentId]>,
         table_archetype_components: Vec<ArchetypeComponentId>,
         sparse_set_archetype_components: Vec<ArchetypeComponentId>,
+        tag_archetype_components: Vec<ArchetypeComponentId>,
     ) -> Self {
-        let mut components =
-            SparseSet::with_capacity(table_components.len() + sparse_set_components.len());
+        let mut components = SparseSet::with_capacity(
+            table_components.len() + sparse_set_components.len() + tag_components.len(),
+        );
         for (component_id, archetype_component_id) in
             table_components.iter().zip(table_archetype_components)
         {
@@ -162,6 +179,18 @@ impl Archetype {
                 },
             );
         }
+
+        for (component_id, archetype_component_id) in
+            tag_components.iter().zip(tag_archetype_components)
+        {
+            components.insert(
+                *component_id,
+                ArchetypeComponentInfo {
+                    storage_type: StorageType::Tag,
+                    archetype_component_id,
+                },
+            );
+        }
         Self {
             id,
             table_info: TableInfo {
@@ -171,9 +200,11 @@ impl Archetype {
             components,
             table_components,
             sparse_set_components,
+            tag_components,
             unique_components: SparseSet::new(),
             entities: Default::default(),
             edges: Default::default(),
+            removed: false,
         }
     }
 
@@ -207,6 +238,11 @@ impl Archetype {
         &self.sparse_set_components
     }
 
+    #[inline]
+    pub fn tag_components(&self) -> &[ComponentId] {
+        &self.tag_components
+    }
+
     #[inline]
     pub fn unique_components(&self) -> &SparseSet<ComponentId, Column> {
         &self.unique_components
@@ -285,6 +321,15 @@ impl Archetype {
         self.entities.is_empty()
     }
 
+    /// Returns `true` if this archetype was removed by
+    /// [`World::prune_empty_storage`](crate::world::World::prune_empty_storage). A removed
+    /// archetype keeps its slot in [`Archetypes`], but has no components and no entities, and
+    /// is not matched by any query.
+    #[inline]
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+
     #[inline]
     pub fn contains(&self, component_id: ComponentId) -> bool {
         self.components.contains(component_id)
@@ -311,21 +356,72 @@ impl Archetype {
         self.entities.clear();
         self.table_info.entity_rows.clear();
     }
+
+    /// Releases the unused capacity of this archetype and its unique components.
+    pub fn shrink_to_fit(&mut self) {
+        self.entities.shrink_to_fit();
+        self.table_info.entity_rows.shrink_to_fit();
+        for column in self.unique_components.values_mut() {
+            column.shrink_to_fit();
+        }
+    }
+
+    /// Returns the memory used by this archetype, not counting the memory used by its unique
+    /// components.
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        let component_lists = [
+            &self.table_components,
+            &self.sparse_set_components,
+            &self.tag_components,
+        ]
+        .into_iter()
+        .map(|components| match components {
+            Cow::Borrowed(_) => MemoryUsage::default(),
+            Cow::Owned(components) => MemoryUsage::of_vec(components),
+        })
+        .sum::<MemoryUsage>();
+        MemoryUsage::of_vec(&self.entities)
+            + MemoryUsage::of_vec(&self.table_info.entity_rows)
+            + self.edges.memory_usage()
+            + component_lists
+            + self.unique_components.memory_usage()
+            + self.components.memory_usage()
+    }
 }
 
 /// A generational id that changes every time the set of archetypes changes
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub struct ArchetypeGeneration(usize);
+pub struct ArchetypeGeneration {
+    len: usize,
+    removals: usize,
+}
 
 impl ArchetypeGeneration {
     #[inline]
     pub const fn initial() -> Self {
-        ArchetypeGeneration(0)
+        ArchetypeGeneration {
+            len: 0,
+            removals: 0,
+        }
     }
 
+    /// Returns the number of archetype ids handed out so far. The archetypes added since an
+    /// older generation are those with an index in `old.value()..new.value()`.
     #[inline]
     pub fn value(self) -> usize {
-        self.0
+        self.len
+    }
+
+    /// Returns the number of times archetypes were removed so far. Anything that stores the ids
+    /// of archetypes or tables must drop the removed ones when this changes.
+    #[inline]
+    pub fn removals(self) -> usize {
+        self.removals
+    }
+
+    #[inline]
+    pub(crate) fn with_removals(self, removals: usize) -> Self {
+        ArchetypeGeneration { removals, ..self }
     }
 }
 
@@ -333,6 +429,7 @@ impl ArchetypeGeneration {
 pub struct ArchetypeIdentity {
     table_components: Cow<'static, [ComponentId]>,
     sparse_set_components: Cow<'static, [ComponentId]>,
+    tag_components: Cow<'static, [ComponentId]>,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -365,6 +462,7 @@ pub struct Archetypes {
     pub(crate) archetypes: Vec<Archetype>,
     pub(crate) archetype_component_count: usize,
     archetype_ids: HashMap<ArchetypeIdentity, ArchetypeId>,
+    removals: usize,
 }
 
 impl Default for Archetypes {
@@ -373,8 +471,9 @@ impl Default for Archetypes {
             archetypes: Vec::new(),
             archetype_ids: Default::default(),
             archetype_component_count: 0,
+            removals: 0,
         };
-        archetypes.get_id_or_insert(TableId::empty(), Vec::new(), Vec::new());
+        archetypes.get_id_or_insert(TableId::empty(), Vec::new(), Vec::new(), Vec::new());
 
         // adds the resource archetype. it is "special" in that it is inaccessible via a "hash",
         // which prevents entities from being added to it
@@ -383,6 +482,8 @@ impl Default for Archetypes {
             TableId::empty(),
             Cow::Owned(Vec::new()),
             Cow::Owned(Vec::new()),
+            Cow::Owned(Vec::new()),
+            Vec::new(),
             Vec::new(),
             Vec::new(),
         ));
@@ -393,9 +494,14 @@ impl Default for Archetypes {
 impl Archetypes {
     #[inline]
     pub fn generation(&self) -> ArchetypeGeneration {
-        ArchetypeGeneration(self.archetypes.len())
+        ArchetypeGeneration {
+            len: self.archetypes.len(),
+            removals: self.removals,
+        }
     }
 
+    /// Returns the number of archetype ids handed out so far, including those of removed
+    /// archetypes.
     #[inline]
     pub fn len(&self) -> usize {
         self.archetypes.len()
@@ -438,12 +544,16 @@ impl Archetypes {
 
     #[inline]
     pub fn get(&self, id: ArchetypeId) -> Option<&Archetype> {
-        self.archetypes.get(id.index())
+        self.archetypes
+            .get(id.index())
+            .filter(|archetype| !archetype.is_removed())
     }
 
     #[inline]
     pub fn get_mut(&mut self, id: ArchetypeId) -> Option<&mut Archetype> {
-        self.archetypes.get_mut(id.index())
+        self.archetypes
+            .get_mut(id.index())
+            .filter(|archetype| !archetype.is_removed())
     }
 
     #[inline]
@@ -461,13 +571,60 @@ impl Archetypes {
         }
     }
 
+    /// Iterates over the archetypes that were not removed.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &Archetype> {
-        self.archetypes.iter()
+        self.archetypes
+            .iter()
+            .filter(|archetype| !archetype.is_removed())
+    }
+
+    /// Removes the given archetypes, which must be empty. Their slots are replaced with
+    /// placeholders that have no components, and their ids are never handed out again: an
+    /// archetype with the same components gets a new id. Bumps the
+    /// [`ArchetypeGeneration::removals`] count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the ids is [`ArchetypeId::EMPTY`] or [`ArchetypeId::RESOURCE`].
+    pub(crate) fn remove(&mut self, ids: &[ArchetypeId]) {
+        if ids.is_empty() {
+            return;
+        }
+        for &id in ids {
+            assert!(
+                id != ArchetypeId::EMPTY && id != ArchetypeId::RESOURCE,
+                "the empty and resource archetypes can't be removed"
+            );
+            let archetype = &mut self.archetypes[id.index()];
+            debug_assert!(archetype.is_empty() && !archetype.is_removed());
+            self.archetype_ids.remove(&ArchetypeIdentity {
+                table_components: archetype.table_components.clone(),
+                sparse_set_components: archetype.sparse_set_components.clone(),
+                tag_components: archetype.tag_components.clone(),
+            });
+            *archetype = Archetype::new(
+                id,
+                TableId::empty(),
+                Cow::Borrowed(&[]),
+                Cow::Borrowed(&[]),
+                Cow::Borrowed(&[]),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            );
+            archetype.removed = true;
+        }
+        // the edges of the remaining archetypes may lead to the removed ones. they are
+        // recomputed when they are needed again.
+        for archetype in self.archetypes.iter_mut() {
+            archetype.edges = Edges::default();
+        }
+        self.removals += 1;
     }
 
     /// Gets the archetype id matching the given inputs or inserts a new one if it doesn't exist.
-    /// `table_components` and `sparse_set_components` must be sorted
+    /// `table_components`, `sparse_set_components` and `tag_components` must be sorted
     ///
     /// # Safety
     /// [`TableId`] must exist in tables
@@ -476,12 +633,15 @@ impl Archetypes {
         table_id: TableId,
         table_components: Vec<ComponentId>,
         sparse_set_components: Vec<ComponentId>,
+        tag_components: Vec<ComponentId>,
     ) -> ArchetypeId {
         let table_components = Cow::from(table_components);
         let sparse_set_components = Cow::from(sparse_set_components);
+        let tag_components = Cow::from(tag_components);
         let archetype_identity = ArchetypeIdentity {
             sparse_set_components: sparse_set_components.clone(),
             table_components: table_components.clone(),
+            tag_components: tag_components.clone(),
         };
 
         let archetypes = &mut self.archetypes;
@@ -502,13 +662,18 @@ impl Archetypes {
                 let sparse_set_archetype_components = (0..sparse_set_components.len())
                     .map(|_| next_archetype_component_id())
                     .collect();
+                let tag_archetype_components = (0..tag_components.len())
+                    .map(|_| next_archetype_component_id())
+                    .collect();
                 archetypes.push(Archetype::new(
                     id,
                     table_id,
                     table_components,
                     sparse_set_components,
+                    tag_components,
                     table_archetype_components,
                     sparse_set_archetype_components,
+                    tag_archetype_components,
                 ));
                 id
             })
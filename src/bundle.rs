@@ -6,7 +6,7 @@ pub use bevy_ecs_macros::Bundle;
 
 use crate::{
     archetype::{AddBundle, Archetype, ArchetypeId, Archetypes, ComponentStatus},
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    component::{Component, ComponentId, ComponentTicks, Components, StorageType, Tick},
     entity::{Entities, Entity, EntityLocation},
     storage::{SparseSetIndex, SparseSets, Storages, Table},
 };
@@ -153,6 +153,8 @@ pub struct BundleInfo {
     pub(crate) id: BundleId,
     pub(crate) component_ids: Vec<ComponentId>,
     pub(crate) storage_types: Vec<StorageType>,
+    /// The drop functions of the components, used to drop the replaced values of tag components.
+    pub(crate) drops: Vec<unsafe fn(*mut u8)>,
 }
 
 impl BundleInfo {
@@ -178,7 +180,7 @@ impl BundleInfo {
         components: &mut Components,
         storages: &'a mut Storages,
         archetype_id: ArchetypeId,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> BundleInserter<'a, 'b> {
         let new_archetype_id =
             self.add_bundle_to_archetype(archetypes, storages, components, archetype_id);
@@ -237,7 +239,7 @@ impl BundleInfo {
         archetypes: &'a mut Archetypes,
         components: &mut Components,
         storages: &'a mut Storages,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> BundleSpawner<'a, 'b> {
         let new_archetype_id =
             self.add_bundle_to_archetype(archetypes, storages, components, ArchetypeId::EMPTY);
@@ -268,7 +270,7 @@ impl BundleInfo {
         add_bundle: &AddBundle,
         entity: Entity,
         table_row: usize,
-        change_tick: u32,
+        change_tick: Tick,
         bundle: T,
     ) {
         // NOTE: get_components calls this closure on each component in "bundle order".
@@ -296,6 +298,15 @@ impl BundleInfo {
                     let sparse_set = sparse_sets.get_mut(component_id).unwrap();
                     sparse_set.insert(entity, component_ptr, change_tick);
                 }
+                StorageType::Tag => {
+                    // tags are zero-sized, so the added value is kept by the archetype alone.
+                    // a replaced tag is indistinguishable from its replacement, drop either one
+                    if let ComponentStatus::Mutated =
+                        add_bundle.bundle_status.get_unchecked(bundle_component)
+                    {
+                        (self.drops[bundle_component])(component_ptr);
+                    }
+                }
             }
             bundle_component += 1;
         });
@@ -316,6 +327,7 @@ impl BundleInfo {
         }
         let mut new_table_components = Vec::new();
         let mut new_sparse_set_components = Vec::new();
+        let mut new_tag_components = Vec::new();
         let mut bundle_status = Vec::with_capacity(self.component_ids.len());
 
         let current_archetype = &mut archetypes[archetype_id];
@@ -329,11 +341,15 @@ impl BundleInfo {
                 match component_info.storage_type() {
                     StorageType::Table => new_table_components.push(component_id),
                     StorageType::SparseSet => new_sparse_set_components.push(component_id),
+                    StorageType::Tag => new_tag_components.push(component_id),
                 }
             }
         }
 
-        if new_table_components.is_empty() && new_sparse_set_components.is_empty() {
+        if new_table_components.is_empty()
+            && new_sparse_set_components.is_empty()
+            && new_tag_components.is_empty()
+        {
             let edges = current_archetype.edges_mut();
             // the archetype does not change when we add this bundle
             edges.insert_add_bundle(self.id, archetype_id, bundle_status);
@@ -342,6 +358,7 @@ impl BundleInfo {
             let table_id;
             let table_components;
             let sparse_set_components;
+            let tag_components;
             // the archetype changes when we add this bundle. prepare the new archetype and storages
             {
                 let current_archetype = &archetypes[archetype_id];
@@ -371,9 +388,22 @@ impl BundleInfo {
                     new_sparse_set_components.sort();
                     new_sparse_set_components
                 };
+
+                tag_components = if new_tag_components.is_empty() {
+                    current_archetype.tag_components().to_vec()
+                } else {
+                    new_tag_components.extend(current_archetype.tag_components());
+                    // sort to ignore order while hashing
+                    new_tag_components.sort();
+                    new_tag_components
+                };
             };
-            let new_archetype_id =
-                archetypes.get_id_or_insert(table_id, table_components, sparse_set_components);
+            let new_archetype_id = archetypes.get_id_or_insert(
+                table_id,
+                table_components,
+                sparse_set_components,
+                tag_components,
+            );
             // add an edge from the old archetype to the new archetype
             archetypes[archetype_id].edges_mut().insert_add_bundle(
                 self.id,
@@ -393,7 +423,7 @@ pub(crate) struct BundleInserter<'a, 'b> {
     sparse_sets: &'a mut SparseSets,
     result: InsertBundleResult<'a>,
     archetypes_ptr: *mut Archetype,
-    change_tick: u32,
+    change_tick: Tick,
 }
 
 pub(crate) enum InsertBundleResult<'a> {
@@ -529,7 +559,7 @@ pub(crate) struct BundleSpawner<'a, 'b> {
     bundle_info: &'b BundleInfo,
     table: &'a mut Table,
     sparse_sets: &'a mut SparseSets,
-    change_tick: u32,
+    change_tick: Tick,
 }
 
 impl<'a, 'b> BundleSpawner<'a, 'b> {
@@ -620,11 +650,13 @@ unsafe fn initialize_bundle(
     components: &mut Components,
 ) -> BundleInfo {
     let mut storage_types = Vec::new();
+    let mut drops = Vec::new();
 
     for &component_id in &component_ids {
         // SAFE: component_id exists and is therefore valid
         let component_info = components.get_info_unchecked(component_id);
         storage_types.push(component_info.storage_type());
+        drops.push(component_info.drop());
     }
 
     let mut deduped = component_ids.clone();
@@ -638,5 +670,6 @@ unsafe fn initialize_bundle(
         id,
         component_ids,
         storage_types,
+        drops,
     }
 }
@@ -1,6 +1,9 @@
 //! Types that detect when their internal data mutate.
 
-use crate::{component::ComponentTicks, system::Resource};
+use crate::{
+    component::{ComponentTicks, Tick},
+    system::Resource,
+};
 use std::ops::{Deref, DerefMut};
 
 /// Types that implement reliable change detection.
@@ -42,6 +45,50 @@ pub trait DetectChanges {
     fn set_changed(&mut self);
 }
 
+/// [`DetectChanges`] types that give mutable access to their value, such as [`ResMut`] and
+/// [`Mut`].
+pub trait DetectChangesMut: DetectChanges {
+    /// The type of the value whose changes are detected.
+    type Inner: ?Sized;
+
+    /// Returns a mutable reference to the value without flagging it as changed.
+    ///
+    /// Use this when a mutation should not be noticed by change detection, e.g. when
+    /// it is undone before the end of the system.
+    fn bypass_change_detection(&mut self) -> &mut Self::Inner;
+
+    /// Overwrites the value with `value` if they are not equal, and only flags it as changed in
+    /// that case. Returns `true` if the value was overwritten.
+    ///
+    /// This avoids triggering change detection when the same value is written over and over.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Score(u32);
+    ///
+    /// fn reset_score_system(mut score: ResMut<Score>) {
+    ///     // only flags `Score` as changed if it was not 0 already
+    ///     score.set_if_neq(Score(0));
+    /// }
+    /// ```
+    #[inline]
+    fn set_if_neq(&mut self, value: Self::Inner) -> bool
+    where
+        Self::Inner: Sized + PartialEq,
+    {
+        let current = self.bypass_change_detection();
+        if *current != value {
+            *current = value;
+            self.set_changed();
+            true
+        } else {
+            false
+        }
+    }
+}
+
 macro_rules! change_detection_impl {
     ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)?) => {
         impl<$($generics),* $(: $traits)?> DetectChanges for $name<$($generics),*> {
@@ -67,6 +114,15 @@ macro_rules! change_detection_impl {
             }
         }
 
+        impl<$($generics),* $(: $traits)?> DetectChangesMut for $name<$($generics),*> {
+            type Inner = $target;
+
+            #[inline]
+            fn bypass_change_detection(&mut self) -> &mut Self::Inner {
+                self.value
+            }
+        }
+
         impl<$($generics),* $(: $traits)?> Deref for $name<$($generics),*> {
             type Target = $target;
 
@@ -100,9 +156,15 @@ macro_rules! change_detection_impl {
     };
 }
 
-macro_rules! impl_into_inner {
+macro_rules! impl_methods {
     ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)?) => {
         impl<$($generics),* $(: $traits)?> $name<$($generics),*> {
+            /// Returns the change tick recording the last time this value was changed.
+            #[inline]
+            pub fn last_changed(&self) -> Tick {
+                self.ticks.component_ticks.changed
+            }
+
             /// Consume `self` and return a mutable reference to the
             /// contained value while marking `self` as "changed".
             #[inline]
@@ -110,6 +172,21 @@ macro_rules! impl_into_inner {
                 self.set_changed();
                 self.value
             }
+
+            /// Maps to a [`Mut`] of a part of the contained value, e.g. one of its fields,
+            /// without marking `self` as "changed".
+            ///
+            /// Changing the returned [`Mut`] marks the whole value as "changed", which makes it
+            /// possible to pass a part of a component or resource to code that expects a [`Mut`].
+            ///
+            /// Mutations made by `f` itself are not detected.
+            #[inline]
+            pub fn map_unchanged<U>(self, f: impl FnOnce(&mut $target) -> &mut U) -> Mut<'a, U> {
+                Mut {
+                    value: f(self.value),
+                    ticks: self.ticks,
+                }
+            }
         }
     };
 }
@@ -131,8 +208,14 @@ macro_rules! impl_debug {
 
 pub(crate) struct Ticks<'a> {
     pub(crate) component_ticks: &'a mut ComponentTicks,
-    pub(crate) last_change_tick: u32,
-    pub(crate) change_tick: u32,
+    pub(crate) last_change_tick: Tick,
+    pub(crate) change_tick: Tick,
+}
+
+pub(crate) struct TicksRef<'a> {
+    pub(crate) component_ticks: &'a ComponentTicks,
+    pub(crate) last_change_tick: Tick,
+    pub(crate) change_tick: Tick,
 }
 
 /// Unique mutable borrow of a resource.
@@ -152,7 +235,7 @@ pub struct ResMut<'a, T: Resource> {
 }
 
 change_detection_impl!(ResMut<'a, T>, T, Resource);
-impl_into_inner!(ResMut<'a, T>, T, Resource);
+impl_methods!(ResMut<'a, T>, T, Resource);
 impl_debug!(ResMut<'a, T>, Resource);
 
 /// Unique borrow of a non-[`Send`] resource.
@@ -173,7 +256,7 @@ pub struct NonSendMut<'a, T: 'static> {
 }
 
 change_detection_impl!(NonSendMut<'a, T>, T,);
-impl_into_inner!(NonSendMut<'a, T>, T,);
+impl_methods!(NonSendMut<'a, T>, T,);
 impl_debug!(NonSendMut<'a, T>,);
 
 /// Unique mutable borrow of an entity's component
@@ -183,5 +266,65 @@ pub struct Mut<'a, T> {
 }
 
 change_detection_impl!(Mut<'a, T>, T,);
-impl_into_inner!(Mut<'a, T>, T,);
-impl_debug!(Mut<'a, T>,);
\ No newline at end of file
+impl_methods!(Mut<'a, T>, T,);
+impl_debug!(Mut<'a, T>,);
+
+/// Shared borrow of an entity's component, which can tell whether the component was added or
+/// changed.
+///
+/// Unlike [`Mut`], this does not need write access to the component, so it can be used in
+/// read-only queries in place of `&T`.
+pub struct Ref<'a, T> {
+    pub(crate) value: &'a T,
+    pub(crate) ticks: TicksRef<'a>,
+}
+
+impl<'a, T> Ref<'a, T> {
+    /// Returns true if (and only if) this value been added since the last execution of this
+    /// system.
+    #[inline]
+    pub fn is_added(&self) -> bool {
+        self.ticks
+            .component_ticks
+            .is_added(self.ticks.last_change_tick, self.ticks.change_tick)
+    }
+
+    /// Returns true if (and only if) this value been changed since the last execution of this
+    /// system.
+    #[inline]
+    pub fn is_changed(&self) -> bool {
+        self.ticks
+            .component_ticks
+            .is_changed(self.ticks.last_change_tick, self.ticks.change_tick)
+    }
+
+    /// Returns the change tick recording the last time this value was changed.
+    #[inline]
+    pub fn last_changed(&self) -> Tick {
+        self.ticks.component_ticks.changed
+    }
+
+    /// Consume `self` and return a shared reference to the contained value.
+    #[inline]
+    pub fn into_inner(self) -> &'a T {
+        self.value
+    }
+}
+
+impl<'a, T> Deref for Ref<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<'a, T> AsRef<T> for Ref<'a, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.value
+    }
+}
+
+impl_debug!(Ref<'a, T>,);
@@ -37,6 +37,7 @@ pub trait Component: Send + Sync + 'static {
 
 pub struct TableStorage;
 pub struct SparseStorage;
+pub struct TagStorage;
 
 pub trait ComponentStorage: sealed::Sealed {
     // because the trait is sealed, those items are private API.
@@ -49,11 +50,15 @@ impl ComponentStorage for TableStorage {
 impl ComponentStorage for SparseStorage {
     const STORAGE_TYPE: StorageType = StorageType::SparseSet;
 }
+impl ComponentStorage for TagStorage {
+    const STORAGE_TYPE: StorageType = StorageType::Tag;
+}
 
 mod sealed {
     pub trait Sealed {}
     impl Sealed for super::TableStorage {}
     impl Sealed for super::SparseStorage {}
+    impl Sealed for super::TagStorage {}
 }
 /// The storage used for a specific component type.
 ///
@@ -73,6 +78,17 @@ pub enum StorageType {
     Table,
     /// Provides fast addition and removal of components, but slower iteration.
     SparseSet,
+    /// For zero-sized marker components. The component only exists in the archetype of the
+    /// entity: it has no data column and no change ticks, and adding or removing it never moves
+    /// the entity to another table.
+    ///
+    /// Because there are no change ticks, queries using `&mut T`, [`Ref<T>`](crate::world::Ref),
+    /// [`Added<T>`](crate::query::Added), [`Changed<T>`](crate::query::Changed) or
+    /// [`ChangeTrackers<T>`](crate::query::ChangeTrackers) panic when they are created for a tag
+    /// component, as does [`World::init_index`](crate::world::World::init_index), and
+    /// [`EntityMut::get_mut`](crate::world::EntityMut::get_mut) returns `None`. Use
+    /// [`StorageType::SparseSet`] for markers that need change detection.
+    Tag,
 }
 
 impl Default for StorageType {
@@ -118,6 +134,14 @@ impl ComponentInfo {
         self.descriptor.storage_type
     }
 
+    /// Returns a pointer to the value of a [`StorageType::Tag`] component. Tags are zero-sized,
+    /// so any non-null pointer aligned for the component points to its value.
+    #[inline]
+    pub(crate) fn tag_ptr(&self) -> *mut u8 {
+        debug_assert_eq!(self.descriptor.storage_type, StorageType::Tag);
+        self.descriptor.layout.align() as *mut u8
+    }
+
     #[inline]
     pub fn is_send_and_sync(&self) -> bool {
         self.descriptor.is_send_and_sync
@@ -175,6 +199,11 @@ impl ComponentDescriptor {
     }
 
     pub fn new<T: Component>() -> Self {
+        assert!(
+            T::Storage::STORAGE_TYPE != StorageType::Tag || std::mem::size_of::<T>() == 0,
+            "Component {} uses tag storage but is not zero-sized",
+            std::any::type_name::<T>()
+        );
         Self {
             name: std::any::type_name::<T>().to_string(),
             storage_type: T::Storage::STORAGE_TYPE,
@@ -292,6 +321,12 @@ impl Components {
         self.indices.get(&type_id).map(|index| ComponentId(*index))
     }
 
+    /// Returns the [`ComponentId`] of `T`, if it has been initialized.
+    #[inline]
+    pub fn component_id<T: Component>(&self) -> Option<ComponentId> {
+        self.get_id(TypeId::of::<T>())
+    }
+
     #[inline]
     pub fn get_resource_id(&self, type_id: TypeId) -> Option<ComponentId> {
         self.resource_indices
@@ -340,17 +375,38 @@ impl Components {
     }
 }
 
+// Declares `Tick` once, so both configurations share its documentation.
+macro_rules! tick_types {
+    ($tick:ty, $atomic_tick:ty) => {
+        /// The type of the change ticks stored in [`ComponentTicks`] and by systems.
+        ///
+        /// This is `u32` by default. With the `u64_ticks` feature it is `u64`, which can't
+        /// realistically wrap around, so
+        /// [`World::check_change_ticks`](crate::world::World::check_change_ticks) never needs to
+        /// clamp old ticks.
+        pub type Tick = $tick;
+
+        pub(crate) type AtomicTick = $atomic_tick;
+    };
+}
+
+#[cfg(not(feature = "u64_ticks"))]
+tick_types!(u32, std::sync::atomic::AtomicU32);
+
+#[cfg(feature = "u64_ticks")]
+tick_types!(u64, std::sync::atomic::AtomicU64);
+
 #[derive(Clone, Debug)]
 pub struct ComponentTicks {
-    pub(crate) added: u32,
-    pub(crate) changed: u32,
+    pub(crate) added: Tick,
+    pub(crate) changed: Tick,
 }
 
 impl ComponentTicks {
     #[inline]
-    pub fn is_added(&self, last_change_tick: u32, change_tick: u32) -> bool {
+    pub fn is_added(&self, last_change_tick: Tick, change_tick: Tick) -> bool {
         // The comparison is relative to `change_tick` so that we can detect changes over the whole
-        // `u32` range. Comparing directly the ticks would limit to half that due to overflow
+        // `Tick` range. Comparing directly the ticks would limit to half that due to overflow
         // handling.
         let component_delta = change_tick.wrapping_sub(self.added);
         let system_delta = change_tick.wrapping_sub(last_change_tick);
@@ -359,21 +415,21 @@ impl ComponentTicks {
     }
 
     #[inline]
-    pub fn is_changed(&self, last_change_tick: u32, change_tick: u32) -> bool {
+    pub fn is_changed(&self, last_change_tick: Tick, change_tick: Tick) -> bool {
         let component_delta = change_tick.wrapping_sub(self.changed);
         let system_delta = change_tick.wrapping_sub(last_change_tick);
 
         component_delta < system_delta
     }
 
-    pub(crate) fn new(change_tick: u32) -> Self {
+    pub(crate) fn new(change_tick: Tick) -> Self {
         Self {
             added: change_tick,
             changed: change_tick,
         }
     }
 
-    pub(crate) fn check_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_ticks(&mut self, change_tick: Tick) {
         check_tick(&mut self.added, change_tick);
         check_tick(&mut self.changed, change_tick);
     }
@@ -391,14 +447,14 @@ impl ComponentTicks {
     /// component_ticks.set_changed(world.read_change_tick());
     /// ```
     #[inline]
-    pub fn set_changed(&mut self, change_tick: u32) {
+    pub fn set_changed(&mut self, change_tick: Tick) {
         self.changed = change_tick;
     }
 }
 
-fn check_tick(last_change_tick: &mut u32, change_tick: u32) {
+fn check_tick(last_change_tick: &mut Tick, change_tick: Tick) {
     let tick_delta = change_tick.wrapping_sub(*last_change_tick);
-    const MAX_DELTA: u32 = (u32::MAX / 4) * 3;
+    const MAX_DELTA: Tick = (Tick::MAX / 4) * 3;
     // Clamp to max delta
     if tick_delta > MAX_DELTA {
         *last_change_tick = change_tick.wrapping_sub(MAX_DELTA);
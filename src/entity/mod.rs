@@ -30,7 +30,9 @@ mod serde;
 pub use self::serde::*;
 pub use map_entities::*;
 
-use crate::{archetype::ArchetypeId, storage::SparseSetIndex};
+use crate::{
+    archetype::ArchetypeId, component::Component, storage::SparseSetIndex, world::MemoryUsage,
+};
 use std::{
     convert::TryFrom,
     fmt, mem,
@@ -165,6 +167,31 @@ impl SparseSetIndex for Entity {
     }
 }
 
+/// A [`Component`] that refers to another entity, such as the target of an attack.
+///
+/// Queries can be joined through such a component with
+/// [`Query::join_on`](crate::system::Query::join_on).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::EntityReference;
+///
+/// #[derive(Component)]
+/// struct Target(Entity);
+///
+/// impl EntityReference for Target {
+///     fn entity(&self) -> Entity {
+///         self.0
+///     }
+/// }
+/// ```
+pub trait EntityReference: Component {
+    /// Returns the referenced entity.
+    fn entity(&self) -> Entity;
+}
+
 /// An [`Iterator`] returning a sequence of [`Entity`] values from
 /// [`Entities::reserve_entities`](crate::entity::Entities::reserve_entities).
 pub struct ReserveEntitiesIterator<'a> {
@@ -555,6 +582,18 @@ impl Entities {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.meta) + MemoryUsage::of_vec(&self.pending)
+    }
+
+    /// Releases the unused capacity of the entity metadata.
+    ///
+    /// The metadata of despawned entities is kept, as their ids are reused by later entities.
+    pub fn shrink_to_fit(&mut self) {
+        self.meta.shrink_to_fit();
+        self.pending.shrink_to_fit();
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
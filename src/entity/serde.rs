@@ -34,4 +34,13 @@ impl<'de> Visitor<'de> for EntityVisitor {
     {
         Ok(Entity::from_raw(v))
     }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u32::try_from(v)
+            .map(Entity::from_raw)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
 }
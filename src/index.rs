@@ -0,0 +1,328 @@
+//! Secondary indexes that look up entities by the value of one of their components.
+//!
+//! An index for the component `T` is created with [`World::init_index`], or automatically when
+//! a system uses the [`Index<T>`] system parameter. It maps every value of `T` to the entities
+//! that have it, so that looking up "the entity whose `NetworkId` is 42" does not need to scan
+//! every entity.
+//!
+//! Removed components and despawned entities are removed from the index right away. Writes
+//! through [`Mut<T>`] can't be observed when they happen, so inserted and mutated components are
+//! picked up through change detection instead: the index is refreshed every time it is accessed,
+//! through [`World::index`] or when a system with an [`Index<T>`] parameter runs. A refresh
+//! checks the change ticks of every entity with a `T` component and re-hashes the changed values,
+//! so its cost grows with the number of entities that have `T`, not only with the number of
+//! changes. The index is stored in the [`World`] privately, so it can't be read without being
+//! refreshed first.
+
+use crate::{
+    archetype::Archetype,
+    component::{Component, ComponentId, ComponentStorage, StorageType, Tick},
+    entity::Entity,
+    query::{Changed, QueryState, WorldQuery},
+    storage::SparseSet,
+    system::{ResMutState, SystemMeta, SystemParam, SystemParamFetch, SystemParamState},
+    world::{Mut, World},
+};
+use bevy_utils::HashMap;
+use std::{hash::Hash, ops::Deref};
+
+/// A [`Component`] that can be indexed by its value.
+///
+/// This is implemented for every component that is [`Eq`], [`Hash`] and [`Clone`].
+pub trait IndexedComponent: Component + Eq + Hash + Clone {}
+impl<T> IndexedComponent for T where T: Component + Eq + Hash + Clone {}
+
+/// Maps every value of the component `T` to the entities that have it.
+///
+/// Accessed through [`World::index`] or the [`Index<T>`] system parameter. See the
+/// [module-level documentation](self) for how the index is kept up to date.
+pub struct ComponentIndex<T: IndexedComponent> {
+    map: IndexMap<T>,
+    changed: QueryState<(Entity, &'static T), Changed<T>>,
+    last_refresh_tick: Tick,
+}
+
+impl<T: IndexedComponent> ComponentIndex<T> {
+    fn new(world: &mut World) -> Self {
+        let mut map = IndexMap::default();
+        for (entity, value) in world.query::<(Entity, &T)>().iter(world) {
+            map.insert(entity, value.clone());
+        }
+        Self {
+            map,
+            changed: world.query_filtered(),
+            last_refresh_tick: world.read_change_tick().wrapping_sub(1),
+        }
+    }
+
+    /// Returns an entity whose `T` component equals `value`.
+    ///
+    /// If several entities have the same value, any one of them may be returned.
+    pub fn get(&self, value: &T) -> Option<Entity> {
+        self.map
+            .values
+            .get(value)
+            .and_then(|entities| entities.first().copied())
+    }
+
+    /// Returns an iterator over the entities whose `T` component equals `value`.
+    pub fn iter(&self, value: &T) -> std::iter::Copied<std::slice::Iter<'_, Entity>> {
+        self.map
+            .values
+            .get(value)
+            .map_or(&[][..], |entities| &entities[..])
+            .iter()
+            .copied()
+    }
+
+    /// Returns the value of the `T` component of `entity`, if it is indexed.
+    pub fn value(&self, entity: Entity) -> Option<&T> {
+        self.map.entities.get(&entity)
+    }
+
+    /// Returns the number of indexed entities.
+    pub fn len(&self) -> usize {
+        self.map.entities.len()
+    }
+
+    /// Returns `true` if no entities are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.map.entities.is_empty()
+    }
+
+    /// Updates the index with the `T` components that were inserted or changed since the last
+    /// refresh.
+    fn refresh(&mut self, world: &World) {
+        let change_tick = world.read_change_tick();
+        self.changed.update_archetypes(world);
+        // SAFE: the query is read only, and callers are allowed to read `T`
+        let changed = unsafe {
+            self.changed
+                .iter_unchecked_manual::<<(Entity, &T) as WorldQuery>::ReadOnlyFetch>(
+                    world,
+                    self.last_refresh_tick,
+                    change_tick,
+                )
+        };
+        for (entity, value) in changed {
+            self.map.insert(entity, value.clone());
+        }
+        // Components can still change during the current tick, so they are checked again by the
+        // next refresh.
+        self.last_refresh_tick = change_tick.wrapping_sub(1);
+    }
+}
+
+/// The resource holding the [`ComponentIndex<T>`].
+///
+/// It is private so that the index can only be reached through [`World::index`] and [`Index<T>`],
+/// which both refresh it first.
+struct IndexResource<T: IndexedComponent>(ComponentIndex<T>);
+
+struct IndexMap<T> {
+    values: HashMap<T, Vec<Entity>>,
+    entities: HashMap<Entity, T>,
+}
+
+impl<T> Default for IndexMap<T> {
+    fn default() -> Self {
+        Self {
+            values: Default::default(),
+            entities: Default::default(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> IndexMap<T> {
+    fn insert(&mut self, entity: Entity, value: T) {
+        if self.entities.get(&entity) == Some(&value) {
+            return;
+        }
+        self.remove(entity);
+        self.values.entry(value.clone()).or_default().push(entity);
+        self.entities.insert(entity, value);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(value) = self.entities.remove(&entity) {
+            let entities = self.values.get_mut(&value).unwrap();
+            let index = entities.iter().position(|e| *e == entity).unwrap();
+            entities.swap_remove(index);
+            if entities.is_empty() {
+                self.values.remove(&value);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.entities.clear();
+    }
+}
+
+/// Functions called by the [`World`] when indexed components are removed.
+#[derive(Clone, Copy)]
+pub(crate) struct IndexHooks {
+    pub(crate) remove: fn(&mut World, Entity),
+    pub(crate) clear: fn(&mut World),
+}
+
+impl IndexHooks {
+    fn new<T: IndexedComponent>() -> Self {
+        Self {
+            remove: |world, entity| {
+                if let Some(mut index) = world.get_resource_mut::<IndexResource<T>>() {
+                    index.0.map.remove(entity);
+                }
+            },
+            clear: |world| {
+                if let Some(mut index) = world.get_resource_mut::<IndexResource<T>>() {
+                    index.0.map.clear();
+                }
+            },
+        }
+    }
+
+    /// Returns the hooks of the indexed components among `component_ids`.
+    pub(crate) fn collect(
+        index_hooks: &SparseSet<ComponentId, IndexHooks>,
+        component_ids: impl Iterator<Item = ComponentId>,
+    ) -> Vec<IndexHooks> {
+        if index_hooks.is_empty() {
+            return Vec::new();
+        }
+        component_ids
+            .filter_map(|component_id| index_hooks.get(component_id).copied())
+            .collect()
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex<T>`] containing every entity with a `T` component, and keeps
+    /// it up to date from now on.
+    ///
+    /// Does nothing if the index already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is a [`StorageType::Tag`] component, as tags have no change ticks to detect
+    /// mutations with.
+    pub fn init_index<T: IndexedComponent>(&mut self) {
+        if T::Storage::STORAGE_TYPE == StorageType::Tag {
+            panic!(
+                "Cannot index the tag component {}, tags have no change ticks",
+                std::any::type_name::<T>()
+            );
+        }
+        if self.contains_resource::<IndexResource<T>>() {
+            return;
+        }
+        let component_id = self.init_component::<T>();
+        let index = ComponentIndex::<T>::new(self);
+        self.insert_resource(IndexResource(index));
+        self.index_hooks
+            .insert(component_id, IndexHooks::new::<T>());
+    }
+
+    /// Returns the up to date [`ComponentIndex<T>`], creating it if it does not exist.
+    ///
+    /// This refreshes the index, see the [module-level documentation](crate::index) for its cost.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component, PartialEq, Eq, Hash, Clone)]
+    /// struct NetworkId(u64);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn().insert(NetworkId(42)).id();
+    /// assert_eq!(world.index::<NetworkId>().get(&NetworkId(42)), Some(entity));
+    /// ```
+    pub fn index<T: IndexedComponent>(&mut self) -> &ComponentIndex<T> {
+        self.init_index::<T>();
+        self.resource_scope(|world, mut index: Mut<IndexResource<T>>| index.0.refresh(world));
+        &self.get_resource::<IndexResource<T>>().unwrap().0
+    }
+}
+
+/// [`SystemParam`] that looks up entities by the value of their `T` component.
+///
+/// Derefs to the [`ComponentIndex<T>`], which is created if it does not exist yet and brought up
+/// to date every time the system runs, see the [module-level documentation](crate::index) for
+/// the cost of that. Since this updates the index, systems that use the same
+/// `Index<T>` do not run in parallel, and they cannot mutate `T`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::index::Index;
+///
+/// #[derive(Component, PartialEq, Eq, Hash, Clone)]
+/// struct Team(u32);
+///
+/// fn count_team_system(teams: Index<Team>) {
+///     println!("team 3 has {} members", teams.iter(&Team(3)).count());
+/// }
+/// # count_team_system.system();
+/// ```
+pub struct Index<'w, T: IndexedComponent> {
+    index: &'w ComponentIndex<T>,
+}
+
+impl<'w, T: IndexedComponent> Deref for Index<'w, T> {
+    type Target = ComponentIndex<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.index
+    }
+}
+
+/// The [`SystemParamState`] of [`Index<T>`].
+pub struct IndexState<T: IndexedComponent> {
+    index: ResMutState<IndexResource<T>>,
+    components: QueryState<&'static T>,
+}
+
+impl<'w, T: IndexedComponent> SystemParam for Index<'w, T> {
+    type Fetch = IndexState<T>;
+}
+
+// SAFE: the index is accessed like `ResMut<IndexResource<T>>`, and `T` is read like a
+// `Query<&T>`. Both apply their access to SystemMeta, and panic if it conflicts with any prior
+// access.
+unsafe impl<T: IndexedComponent> SystemParamState for IndexState<T> {
+    type Config = ();
+
+    fn init(world: &mut World, system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
+        world.init_index::<T>();
+        Self {
+            index: ResMutState::init(world, system_meta, ()),
+            components: SystemParamState::init(world, system_meta, ()),
+        }
+    }
+
+    fn new_archetype(&mut self, archetype: &Archetype, system_meta: &mut SystemMeta) {
+        SystemParamState::new_archetype(&mut self.components, archetype, system_meta);
+    }
+
+    fn default_config() {}
+}
+
+impl<'w, 's, T: IndexedComponent> SystemParamFetch<'w, 's> for IndexState<T> {
+    type Item = Index<'w, T>;
+
+    #[inline]
+    unsafe fn get_param(
+        state: &'s mut Self,
+        system_meta: &SystemMeta,
+        world: &'w World,
+        change_tick: Tick,
+    ) -> Self::Item {
+        let index = &mut ResMutState::get_param(&mut state.index, system_meta, world, change_tick)
+            .into_inner()
+            .0;
+        index.refresh(world);
+        Index { index }
+    }
+}
@@ -6,10 +6,13 @@ pub mod change_detection;
 pub mod component;
 pub mod entity;
 pub mod event;
+pub mod index;
 pub mod query;
 pub mod schedule;
 pub mod storage;
 pub mod system;
+pub mod task;
+pub mod task_pool;
 pub mod world;
 
 /// Most commonly used re-exported types.
@@ -17,21 +20,22 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         bundle::Bundle,
-        change_detection::DetectChanges,
+        change_detection::{DetectChanges, DetectChangesMut},
         component::Component,
         entity::Entity,
         event::{EventReader, EventWriter},
-        query::{Added, ChangeTrackers, Changed, Or, QueryState, With, Without},
+        query::{Added, AnyOf, ChangeTrackers, Changed, Has, Or, QueryState, With, Without},
         schedule::{
             AmbiguitySetLabel, ExclusiveSystemDescriptorCoercion, ParallelSystemDescriptorCoercion,
             RunCriteria, RunCriteriaDescriptorCoercion, RunCriteriaLabel, RunCriteriaPiping,
             Schedule, Stage, StageLabel, State, SystemLabel, SystemSet, SystemStage,
         },
         system::{
-            Commands, ConfigurableSystem, In, IntoChainSystem, IntoExclusiveSystem, IntoSystem,
-            Local, NonSend, NonSendMut, Query, QuerySet, RemovedComponents, Res, ResMut, System,
+            Commands, ConfigurableSystem, Deferred, In, IntoChainSystem, IntoExclusiveSystem,
+            IntoSystem, Local, NonSend, NonSendMut, Query, QuerySet, RemovedComponents,
+            RemovedValues, Res, ResMut, System,
         },
-        world::{FromWorld, Mut, World},
+        world::{FromWorld, Mut, Ref, World},
     };
 }
 
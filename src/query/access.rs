@@ -98,6 +98,15 @@ impl<T: SparseSetIndex> Access<T> {
         }
     }
 
+    /// Returns true if every access of this `Access` is also granted by `other`.
+    pub fn is_subset(&self, other: &Access<T>) -> bool {
+        if self.reads_all && !other.reads_all {
+            return false;
+        }
+        self.writes.is_subset(&other.writes)
+            && (other.reads_all || self.reads_and_writes.is_subset(&other.reads_and_writes))
+    }
+
     /// Calculates conflicting accesses between this `Access` and `other`.
     pub fn get_conflicts(&self, other: &Access<T>) -> Vec<T> {
         let mut conflicts = FixedBitSet::default();
@@ -186,6 +195,25 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.with.union_with(&access.with);
         self.without.union_with(&access.without);
     }
+
+    /// Returns true if every access of this `FilteredAccess` is also granted by `other`, and it
+    /// keeps all of the `with` and `without` filters of `other`.
+    pub fn is_subset(&self, other: &FilteredAccess<T>) -> bool {
+        self.access.is_subset(&other.access)
+            && other.with.is_subset(&self.with)
+            && other.without.is_subset(&self.without)
+    }
+
+    /// Adds the `with` and `without` filters of `access`, without its reads and writes.
+    pub fn extend_filters(&mut self, access: &FilteredAccess<T>) {
+        self.with.union_with(&access.with);
+        self.without.union_with(&access.without);
+    }
+
+    /// Adds the reads and writes of `access`, without its `with` and `without` filters.
+    pub fn extend_access(&mut self, access: &FilteredAccess<T>) {
+        self.access.extend(&access.access);
+    }
 }
 
 pub struct FilteredAccessSet<T: SparseSetIndex> {
@@ -204,19 +232,40 @@ impl<T: SparseSetIndex> FilteredAccessSet<T> {
         &mut self.combined_access
     }
 
+    /// Calculates all conflicting accesses between `filtered_access` and the accesses in this set.
     pub fn get_conflicts(&self, filtered_access: &FilteredAccess<T>) -> Vec<T> {
-        // if combined unfiltered access is incompatible, check each filtered access for
-        // compatibility
-        if !filtered_access.access.is_compatible(&self.combined_access) {
-            for current_filtered_access in self.filtered_accesses.iter() {
-                if !current_filtered_access.is_compatible(filtered_access) {
-                    return current_filtered_access
-                        .access
-                        .get_conflicts(&filtered_access.access);
-                }
-            }
+        let mut conflicts = FixedBitSet::default();
+        for (_, access_conflicts) in self.get_conflicts_per_access(filtered_access) {
+            conflicts.extend(
+                access_conflicts
+                    .iter()
+                    .map(SparseSetIndex::sparse_set_index),
+            );
         }
-        Vec::new()
+        conflicts
+            .ones()
+            .map(SparseSetIndex::get_sparse_set_index)
+            .collect()
+    }
+
+    /// Calculates conflicting accesses between `filtered_access` and each access in this set.
+    ///
+    /// Returns the position (in insertion order) of every conflicting access in this set, paired
+    /// with the values it conflicts on.
+    pub fn get_conflicts_per_access(
+        &self,
+        filtered_access: &FilteredAccess<T>,
+    ) -> Vec<(usize, Vec<T>)> {
+        // if combined unfiltered access is compatible, no filtered access can conflict
+        if filtered_access.access.is_compatible(&self.combined_access) {
+            return Vec::new();
+        }
+        self.filtered_accesses
+            .iter()
+            .enumerate()
+            .filter(|(_, current)| !current.is_compatible(filtered_access))
+            .map(|(index, current)| (index, current.access.get_conflicts(&filtered_access.access)))
+            .collect()
     }
 
     pub fn add(&mut self, filtered_access: FilteredAccess<T>) {
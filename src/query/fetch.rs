@@ -1,16 +1,21 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId},
-    change_detection::Ticks,
-    component::{Component, ComponentId, ComponentStorage, ComponentTicks, StorageType},
+    change_detection::{Ref, Ticks, TicksRef},
+    component::{
+        Component, ComponentId, ComponentStorage, ComponentTicks, SparseStorage, StorageType,
+        TableStorage, TagStorage, Tick,
+    },
     entity::Entity,
     query::{Access, FilteredAccess},
     storage::{ComponentSparseSet, Table, Tables},
     world::{Mut, World},
 };
 use bevy_ecs_macros::all_tuples;
+pub use bevy_ecs_macros::WorldQuery;
 use std::{
     cell::UnsafeCell,
     marker::PhantomData,
+    ops::{Deref, DerefMut, Range},
     ptr::{self, NonNull},
 };
 
@@ -27,10 +32,13 @@ use std::{
 /// [`Component`] and `WQ` stands for a [`WorldQuery`]:
 /// - `&C`: Queries immutably for the component `C`
 /// - `&mut C`: Queries mutably for the component `C`
+/// - `Ref<C>`: Queries immutably for the component `C`, along with its change ticks. See [`Ref`].
 /// - `Option<WQ>`: Queries the inner [`WorldQuery`] `WQ` but instead of discarding the entity if the world
 ///     query fails it returns [`None`]. See [`Query`](crate::system::Query).
 /// - `(WQ1, WQ2, ...)`: Queries all contained world queries allowing to query for more than one thing.
 ///     This is the `And` operator for filters. See [`Or`].
+/// - `Has<C>`: Returns whether the entity has the component `C`, without accessing it. See [`Has`].
+/// - `AnyOf<(WQ1, WQ2, ...)>`: Like `Option<WQ>` for each world query, but at least one must match. See [`AnyOf`].
 /// - `ChangeTrackers<C>`: See the docs of [`ChangeTrackers`].
 /// - [`Entity`]: Using the entity type as a world query will grant access to the entity that is
 ///     being queried for. See [`Entity`].
@@ -39,6 +47,39 @@ use std::{
 /// [`With`](crate::query::With), [`Without`](crate::query::Without) and [`Or`].
 /// For more information on these consult the item's corresponding documentation.
 ///
+/// # Derive
+///
+/// This trait can be derived for structs with named fields, where every field is itself a
+/// [`WorldQuery`] (including other derived queries). The derive generates an `{Name}Item`
+/// struct with the same field names that is returned when iterating the query. Queries with
+/// mutable fields require the `#[world_query(mutable)]` attribute, which also generates an
+/// `{Name}ReadOnlyItem` returned by read-only iteration.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::query::WorldQuery;
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+/// #[derive(Component)]
+/// struct Velocity(f32);
+///
+/// #[derive(WorldQuery)]
+/// #[world_query(mutable)]
+/// struct Movement<'w> {
+///     entity: Entity,
+///     position: &'w mut Position,
+///     velocity: Option<&'w Velocity>,
+/// }
+///
+/// fn movement_system(mut query: Query<Movement>) {
+///     for mut movement in query.iter_mut() {
+///         movement.position.0 += movement.velocity.map_or(0.0, |velocity| velocity.0);
+///     }
+/// }
+/// # movement_system.system();
+/// ```
+///
 /// [`Or`]: crate::query::Or
 pub trait WorldQuery {
     type Fetch: for<'world, 'state> Fetch<'world, 'state, State = Self::State>;
@@ -62,8 +103,8 @@ pub trait Fetch<'world, 'state>: Sized {
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self;
 
     /// Returns true if (and only if) every table of every archetype matched by this Fetch contains
@@ -122,6 +163,18 @@ pub trait Fetch<'world, 'state>: Sized {
 /// [`Fetch::table_fetch`].
 pub unsafe trait FetchState: Send + Sync + Sized {
     fn init(world: &mut World) -> Self;
+    /// Creates the state like [`FetchState::init`], without initializing anything in `world`.
+    ///
+    /// Returns `None` if a component used by the state has not been initialized yet. A returned
+    /// state must be identical to the one [`FetchState::init`] would create for `world`.
+    ///
+    /// This is only used to transmute queries, see
+    /// [`Query::transmute_lens`](crate::system::Query::transmute_lens). The default
+    /// implementation always returns `None`, so queries can't be transmuted into a query using
+    /// this state.
+    fn try_init(_world: &World) -> Option<Self> {
+        None
+    }
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>);
     fn update_archetype_component_access(
         &self,
@@ -163,6 +216,10 @@ unsafe impl FetchState for EntityState {
         Self
     }
 
+    fn try_init(_world: &World) -> Option<Self> {
+        Some(Self)
+    }
+
     fn update_component_access(&self, _access: &mut FilteredAccess<ComponentId>) {}
 
     fn update_archetype_component_access(
@@ -192,8 +249,8 @@ impl<'w, 's> Fetch<'w, 's> for EntityFetch {
     unsafe fn init(
         _world: &World,
         _state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         Self {
             entities: std::ptr::null::<Entity>(),
@@ -249,6 +306,13 @@ unsafe impl<T: Component> FetchState for ReadState<T> {
         }
     }
 
+    fn try_init(world: &World) -> Option<Self> {
+        Some(ReadState {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
         if access.access().has_write(self.component_id) {
             panic!("&{} conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.",
@@ -278,6 +342,19 @@ unsafe impl<T: Component> FetchState for ReadState<T> {
     }
 }
 
+/// Panics if `T` is a [`StorageType::Tag`] component, which has no change ticks for `query` to
+/// read.
+#[inline]
+pub(crate) fn assert_has_change_ticks<T: Component>(query: &str) {
+    if T::Storage::STORAGE_TYPE == StorageType::Tag {
+        panic!(
+            "{} can't be used with the tag component {}, tags have no change ticks",
+            query,
+            std::any::type_name::<T>()
+        );
+    }
+}
+
 /// The [`Fetch`] of `&T`.
 pub struct ReadFetch<T> {
     table_components: NonNull<T>,
@@ -307,15 +384,15 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         let mut value = Self {
             table_components: NonNull::dangling(),
@@ -349,6 +426,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadFetch<T> {
                 self.table_components = column.get_data_ptr().cast::<T>();
             }
             StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+            StorageType::Tag => {}
         }
     }
 
@@ -372,6 +450,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadFetch<T> {
                 let entity = *self.entities.add(archetype_index);
                 &*(*self.sparse_set).get(entity).unwrap().cast::<T>()
             }
+            // tags are zero-sized, so any aligned pointer points to their value
+            StorageType::Tag => &*self.table_components.as_ptr(),
         }
     }
 
@@ -394,8 +474,8 @@ pub struct WriteFetch<T> {
     entities: *const Entity,
     entity_table_rows: *const usize,
     sparse_set: *const ComponentSparseSet,
-    last_change_tick: u32,
-    change_tick: u32,
+    last_change_tick: Tick,
+    change_tick: Tick,
 }
 
 impl<T> Clone for WriteFetch<T> {
@@ -444,6 +524,7 @@ pub struct WriteState<T> {
 // written
 unsafe impl<T: Component> FetchState for WriteState<T> {
     fn init(world: &mut World) -> Self {
+        assert_has_change_ticks::<T>("&mut");
         let component_id = world.init_component::<T>();
         WriteState {
             component_id,
@@ -451,6 +532,14 @@ unsafe impl<T: Component> FetchState for WriteState<T> {
         }
     }
 
+    fn try_init(world: &World) -> Option<Self> {
+        assert_has_change_ticks::<T>("&mut");
+        Some(WriteState {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
         if access.access().has_read(self.component_id) {
             panic!("&mut {} conflicts with a previous access in this query. Mutable component access must be unique.",
@@ -487,15 +576,15 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WriteFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         let mut value = Self {
             table_components: NonNull::dangling(),
@@ -533,6 +622,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WriteFetch<T> {
                 self.table_ticks = column.get_ticks_ptr();
             }
             StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+            StorageType::Tag => {}
         }
     }
 
@@ -570,6 +660,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WriteFetch<T> {
                     },
                 }
             }
+            // rejected by the state, tags have no change ticks
+            StorageType::Tag => unreachable!(),
         }
     }
 
@@ -593,15 +685,15 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadOnlyWriteFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         let mut value = Self {
             table_components: NonNull::dangling(),
@@ -635,6 +727,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadOnlyWriteFetch<T> {
                 self.table_components = column.get_data_ptr().cast::<T>();
             }
             StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+            StorageType::Tag => {}
         }
     }
 
@@ -655,6 +748,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadOnlyWriteFetch<T> {
                 let entity = *self.entities.add(archetype_index);
                 &*(*self.sparse_set).get(entity).unwrap().cast::<T>()
             }
+            // rejected by the state, tags have no change ticks
+            StorageType::Tag => unreachable!(),
         }
     }
 
@@ -664,6 +759,197 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ReadOnlyWriteFetch<T> {
     }
 }
 
+impl<'a, T: Component> WorldQuery for Ref<'a, T> {
+    type Fetch = RefFetch<T>;
+    type State = RefState<T>;
+    type ReadOnlyFetch = RefFetch<T>;
+}
+
+/// The [`FetchState`] of [`Ref<T>`].
+pub struct RefState<T> {
+    component_id: ComponentId,
+    marker: PhantomData<T>,
+}
+
+// SAFETY: component access and archetype component access are properly updated to reflect that T is
+// read
+unsafe impl<T: Component> FetchState for RefState<T> {
+    fn init(world: &mut World) -> Self {
+        assert_has_change_ticks::<T>("Ref");
+        let component_id = world.init_component::<T>();
+        RefState {
+            component_id,
+            marker: PhantomData,
+        }
+    }
+
+    fn try_init(world: &World) -> Option<Self> {
+        assert_has_change_ticks::<T>("Ref");
+        Some(RefState {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
+    fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
+        if access.access().has_write(self.component_id) {
+            panic!("Ref<{}> conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.",
+                std::any::type_name::<T>());
+        }
+        access.add_read(self.component_id)
+    }
+
+    fn update_archetype_component_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        if let Some(archetype_component_id) =
+            archetype.get_archetype_component_id(self.component_id)
+        {
+            access.add_read(archetype_component_id);
+        }
+    }
+
+    fn matches_archetype(&self, archetype: &Archetype) -> bool {
+        archetype.contains(self.component_id)
+    }
+
+    fn matches_table(&self, table: &Table) -> bool {
+        table.has_column(self.component_id)
+    }
+}
+
+/// The [`Fetch`] of [`Ref<T>`].
+pub struct RefFetch<T> {
+    table_components: NonNull<T>,
+    table_ticks: *const ComponentTicks,
+    entities: *const Entity,
+    entity_table_rows: *const usize,
+    sparse_set: *const ComponentSparseSet,
+    last_change_tick: Tick,
+    change_tick: Tick,
+}
+
+impl<T> Clone for RefFetch<T> {
+    fn clone(&self) -> Self {
+        Self {
+            table_components: self.table_components,
+            table_ticks: self.table_ticks,
+            entities: self.entities,
+            entity_table_rows: self.entity_table_rows,
+            sparse_set: self.sparse_set,
+            last_change_tick: self.last_change_tick,
+            change_tick: self.change_tick,
+        }
+    }
+}
+
+/// SAFETY: access is read only
+unsafe impl<T> ReadOnlyFetch for RefFetch<T> {}
+
+impl<'w, 's, T: Component> Fetch<'w, 's> for RefFetch<T> {
+    type Item = Ref<'w, T>;
+    type State = RefState<T>;
+
+    const IS_DENSE: bool = {
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => true,
+            StorageType::SparseSet | StorageType::Tag => false,
+        }
+    };
+
+    unsafe fn init(
+        world: &World,
+        state: &Self::State,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        let mut value = Self {
+            table_components: NonNull::dangling(),
+            table_ticks: ptr::null::<ComponentTicks>(),
+            entities: ptr::null::<Entity>(),
+            entity_table_rows: ptr::null::<usize>(),
+            sparse_set: ptr::null::<ComponentSparseSet>(),
+            last_change_tick,
+            change_tick,
+        };
+        if T::Storage::STORAGE_TYPE == StorageType::SparseSet {
+            value.sparse_set = world
+                .storages()
+                .sparse_sets
+                .get(state.component_id)
+                .unwrap();
+        }
+        value
+    }
+
+    #[inline]
+    unsafe fn set_archetype(
+        &mut self,
+        state: &Self::State,
+        archetype: &Archetype,
+        tables: &Tables,
+    ) {
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => {
+                self.entity_table_rows = archetype.entity_table_rows().as_ptr();
+                let column = tables[archetype.table_id()]
+                    .get_column(state.component_id)
+                    .unwrap();
+                self.table_components = column.get_data_ptr().cast::<T>();
+                self.table_ticks = column.get_ticks_const_ptr();
+            }
+            StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+            StorageType::Tag => {}
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table(&mut self, state: &Self::State, table: &Table) {
+        let column = table.get_column(state.component_id).unwrap();
+        self.table_components = column.get_data_ptr().cast::<T>();
+        self.table_ticks = column.get_ticks_const_ptr();
+    }
+
+    #[inline]
+    unsafe fn archetype_fetch(&mut self, archetype_index: usize) -> Self::Item {
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => {
+                let table_row = *self.entity_table_rows.add(archetype_index);
+                self.table_fetch(table_row)
+            }
+            StorageType::SparseSet => {
+                let entity = *self.entities.add(archetype_index);
+                let (component, component_ticks) =
+                    (*self.sparse_set).get_with_ticks(entity).unwrap();
+                Ref {
+                    value: &*component.cast::<T>(),
+                    ticks: TicksRef {
+                        component_ticks: &*component_ticks,
+                        last_change_tick: self.last_change_tick,
+                        change_tick: self.change_tick,
+                    },
+                }
+            }
+            // rejected by the state, tags have no change ticks
+            StorageType::Tag => unreachable!(),
+        }
+    }
+
+    #[inline]
+    unsafe fn table_fetch(&mut self, table_row: usize) -> Self::Item {
+        Ref {
+            value: &*self.table_components.as_ptr().add(table_row),
+            ticks: TicksRef {
+                component_ticks: &*self.table_ticks.add(table_row),
+                last_change_tick: self.last_change_tick,
+                change_tick: self.change_tick,
+            },
+        }
+    }
+}
+
 impl<T: WorldQuery> WorldQuery for Option<T> {
     type Fetch = OptionFetch<T::Fetch>;
     type State = OptionState<T::State>;
@@ -694,6 +980,12 @@ unsafe impl<T: FetchState> FetchState for OptionState<T> {
         }
     }
 
+    fn try_init(world: &World) -> Option<Self> {
+        Some(Self {
+            state: T::try_init(world)?,
+        })
+    }
+
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
         self.state.update_component_access(access);
     }
@@ -727,8 +1019,8 @@ impl<'w, 's, T: Fetch<'w, 's>> Fetch<'w, 's> for OptionFetch<T> {
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         Self {
             fetch: T::init(world, &state.state, last_change_tick, change_tick),
@@ -776,109 +1068,399 @@ impl<'w, 's, T: Fetch<'w, 's>> Fetch<'w, 's> for OptionFetch<T> {
     }
 }
 
-/// [`WorldQuery`] that tracks changes and additions for component `T`.
-///
-/// Wraps a [`Component`] to track whether the component changed for the corresponding entities in
-/// a query since the last time the system that includes these queries ran.
+/// [`WorldQuery`] that returns whether an entity has the component `T`.
 ///
-/// If you only care about entities that changed or that got added use the
-/// [`Changed`](crate::query::Changed) and [`Added`](crate::query::Added) filters instead.
+/// Unlike `Option<&T>`, this does not read `T`, so it does not conflict with queries or systems
+/// that access `T` mutably. It also matches every entity, unlike the [`With`](crate::query::With)
+/// and [`Without`](crate::query::Without) filters.
 ///
 /// # Examples
 ///
 /// ```
-/// # use bevy_ecs::component::Component;
-/// # use bevy_ecs::query::ChangeTrackers;
-/// # use bevy_ecs::system::IntoSystem;
-/// # use bevy_ecs::system::Query;
+/// # use bevy_ecs::prelude::*;
 /// #
-/// # #[derive(Component, Debug)]
-/// # struct Name {};
 /// # #[derive(Component)]
-/// # struct Transform {};
+/// # struct Name(String);
+/// # #[derive(Component)]
+/// # struct Invincible;
 /// #
-/// fn print_moving_objects_system(query: Query<(&Name, ChangeTrackers<Transform>)>) {
-///     for (name, tracker) in query.iter() {
-///         if tracker.is_changed() {
-///             println!("Entity moved: {:?}", name);
-///         } else {
-///             println!("Entity stood still: {:?}", name);
-///         }
+/// fn print_invincible_system(query: Query<(&Name, Has<Invincible>)>) {
+///     for (name, is_invincible) in query.iter() {
+///         println!("{} is invincible: {}", name.0, is_invincible);
 ///     }
 /// }
-/// # print_moving_objects_system.system();
+/// # print_invincible_system.system();
 /// ```
-#[derive(Clone)]
-pub struct ChangeTrackers<T: Component> {
-    pub(crate) component_ticks: ComponentTicks,
-    pub(crate) last_change_tick: u32,
-    pub(crate) change_tick: u32,
-    marker: PhantomData<T>,
-}
-
-impl<T: Component> std::fmt::Debug for ChangeTrackers<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ChangeTrackers")
-            .field("component_ticks", &self.component_ticks)
-            .field("last_change_tick", &self.last_change_tick)
-            .field("change_tick", &self.change_tick)
-            .finish()
-    }
-}
-
-impl<T: Component> ChangeTrackers<T> {
-    /// Returns true if this component has been added since the last execution of this system.
-    pub fn is_added(&self) -> bool {
-        self.component_ticks
-            .is_added(self.last_change_tick, self.change_tick)
-    }
-
-    /// Returns true if this component has been changed since the last execution of this system.
-    pub fn is_changed(&self) -> bool {
-        self.component_ticks
-            .is_changed(self.last_change_tick, self.change_tick)
-    }
-}
+pub struct Has<T>(PhantomData<T>);
 
-impl<T: Component> WorldQuery for ChangeTrackers<T> {
-    type Fetch = ChangeTrackersFetch<T>;
-    type State = ChangeTrackersState<T>;
-    type ReadOnlyFetch = ChangeTrackersFetch<T>;
+impl<T: Component> WorldQuery for Has<T> {
+    type Fetch = HasFetch<T>;
+    type State = HasState<T>;
+    type ReadOnlyFetch = HasFetch<T>;
 }
 
-/// The [`FetchState`] of [`ChangeTrackers`].
-pub struct ChangeTrackersState<T> {
+/// The [`FetchState`] of [`Has`].
+pub struct HasState<T> {
     component_id: ComponentId,
     marker: PhantomData<T>,
 }
 
-// SAFETY: component access and archetype component access are properly updated to reflect that T is
-// read
-unsafe impl<T: Component> FetchState for ChangeTrackersState<T> {
+// SAFETY: no component data is accessed, only the archetype's component set is read
+unsafe impl<T: Component> FetchState for HasState<T> {
     fn init(world: &mut World) -> Self {
-        let component_id = world.init_component::<T>();
         Self {
-            component_id,
+            component_id: world.init_component::<T>(),
             marker: PhantomData,
         }
     }
 
-    fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
-        if access.access().has_write(self.component_id) {
-            panic!("ChangeTrackers<{}> conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.",
-                std::any::type_name::<T>());
-        }
-        access.add_read(self.component_id)
+    fn try_init(world: &World) -> Option<Self> {
+        Some(Self {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
     }
 
+    fn update_component_access(&self, _access: &mut FilteredAccess<ComponentId>) {}
+
     fn update_archetype_component_access(
         &self,
-        archetype: &Archetype,
-        access: &mut Access<ArchetypeComponentId>,
+        _archetype: &Archetype,
+        _access: &mut Access<ArchetypeComponentId>,
     ) {
-        if let Some(archetype_component_id) =
-            archetype.get_archetype_component_id(self.component_id)
-        {
+    }
+
+    fn matches_archetype(&self, _archetype: &Archetype) -> bool {
+        true
+    }
+
+    fn matches_table(&self, _table: &Table) -> bool {
+        true
+    }
+}
+
+/// The [`Fetch`] of [`Has`].
+pub struct HasFetch<T> {
+    has: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> Clone for HasFetch<T> {
+    fn clone(&self) -> Self {
+        Self {
+            has: self.has,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// SAFETY: access is read only
+unsafe impl<T> ReadOnlyFetch for HasFetch<T> {}
+
+impl<'w, 's, T: Component> Fetch<'w, 's> for HasFetch<T> {
+    type Item = bool;
+    type State = HasState<T>;
+
+    const IS_DENSE: bool = {
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => true,
+            StorageType::SparseSet | StorageType::Tag => false,
+        }
+    };
+
+    unsafe fn init(
+        _world: &World,
+        _state: &Self::State,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
+    ) -> Self {
+        Self {
+            has: false,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    unsafe fn set_archetype(
+        &mut self,
+        state: &Self::State,
+        archetype: &Archetype,
+        _tables: &Tables,
+    ) {
+        self.has = archetype.contains(state.component_id);
+    }
+
+    #[inline]
+    unsafe fn set_table(&mut self, state: &Self::State, table: &Table) {
+        self.has = table.has_column(state.component_id);
+    }
+
+    #[inline]
+    unsafe fn archetype_fetch(&mut self, _archetype_index: usize) -> Self::Item {
+        self.has
+    }
+
+    #[inline]
+    unsafe fn table_fetch(&mut self, _table_row: usize) -> Self::Item {
+        self.has
+    }
+}
+
+/// [`WorldQuery`] that matches entities that match at least one of the queries in the tuple `T`,
+/// returning a tuple of [`Option`]s.
+///
+/// `AnyOf<(&A, &B)>` is similar to `(Option<&A>, Option<&B>)`, except that entities with neither
+/// `A` nor `B` are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Health(f32);
+/// # #[derive(Component)]
+/// # struct Shield(f32);
+/// #
+/// fn print_defense_system(query: Query<AnyOf<(&Health, &Shield)>>) {
+///     for (health, shield) in query.iter() {
+///         let health = health.map_or(0.0, |health| health.0);
+///         let shield = shield.map_or(0.0, |shield| shield.0);
+///         println!("defense: {}", health + shield);
+///     }
+/// }
+/// # print_defense_system.system();
+/// ```
+#[derive(Clone)]
+pub struct AnyOf<T>(T);
+
+macro_rules! impl_any_of_fetch {
+    ($(($name: ident, $state: ident)),*) => {
+        impl<$($name: WorldQuery),*> WorldQuery for AnyOf<($($name,)*)> {
+            type Fetch = AnyOf<($(OptionFetch<$name::Fetch>,)*)>;
+            type State = AnyOf<($($name::State,)*)>;
+            type ReadOnlyFetch = AnyOf<($(OptionFetch<$name::ReadOnlyFetch>,)*)>;
+        }
+
+        /// SAFETY: each item in the tuple is read only
+        unsafe impl<$($name: ReadOnlyFetch),*> ReadOnlyFetch for AnyOf<($(OptionFetch<$name>,)*)> {}
+
+        #[allow(non_snake_case)]
+        impl<'w, 's, $($name: Fetch<'w, 's>),*> Fetch<'w, 's> for AnyOf<($(OptionFetch<$name>,)*)> {
+            type Item = ($(Option<$name::Item>,)*);
+            type State = AnyOf<($($name::State,)*)>;
+
+            #[allow(clippy::unused_unit)]
+            unsafe fn init(_world: &World, state: &Self::State, _last_change_tick: Tick, _change_tick: Tick) -> Self {
+                let ($($name,)*) = &state.0;
+                AnyOf(($(OptionFetch {
+                    fetch: $name::init(_world, $name, _last_change_tick, _change_tick),
+                    matches: false,
+                },)*))
+            }
+
+            const IS_DENSE: bool = true $(&& $name::IS_DENSE)*;
+
+            #[inline]
+            unsafe fn set_archetype(&mut self, _state: &Self::State, _archetype: &Archetype, _tables: &Tables) {
+                let ($($name,)*) = &mut self.0;
+                let ($($state,)*) = &_state.0;
+                $(
+                    $name.matches = $state.matches_archetype(_archetype);
+                    if $name.matches {
+                        $name.fetch.set_archetype($state, _archetype, _tables);
+                    }
+                )*
+            }
+
+            #[inline]
+            unsafe fn set_table(&mut self, _state: &Self::State, _table: &Table) {
+                let ($($name,)*) = &mut self.0;
+                let ($($state,)*) = &_state.0;
+                $(
+                    $name.matches = $state.matches_table(_table);
+                    if $name.matches {
+                        $name.fetch.set_table($state, _table);
+                    }
+                )*
+            }
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            unsafe fn table_fetch(&mut self, _table_row: usize) -> Self::Item {
+                let ($($name,)*) = &mut self.0;
+                ($($name.matches.then(|| $name.fetch.table_fetch(_table_row)),)*)
+            }
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            unsafe fn archetype_fetch(&mut self, _archetype_index: usize) -> Self::Item {
+                let ($($name,)*) = &mut self.0;
+                ($($name.matches.then(|| $name.fetch.archetype_fetch(_archetype_index)),)*)
+            }
+        }
+
+        // SAFETY: update_component_access and update_archetype_component_access are called for
+        // each item in the tuple. Only the reads and writes of the items are added to the access,
+        // since an entity only has to match one of them.
+        #[allow(non_snake_case)]
+        #[allow(clippy::unused_unit)]
+        unsafe impl<$($name: FetchState),*> FetchState for AnyOf<($($name,)*)> {
+            fn init(_world: &mut World) -> Self {
+                AnyOf(($($name::init(_world),)*))
+            }
+
+            fn try_init(_world: &World) -> Option<Self> {
+                Some(AnyOf(($($name::try_init(_world)?,)*)))
+            }
+
+            fn update_component_access(&self, _access: &mut FilteredAccess<ComponentId>) {
+                let ($($name,)*) = &self.0;
+                let mut _intermediate = _access.clone();
+                $(
+                    let mut access = _intermediate.clone();
+                    $name.update_component_access(&mut access);
+                    _intermediate.extend_access(&access);
+                )*
+                *_access = _intermediate;
+            }
+
+            fn update_archetype_component_access(&self, _archetype: &Archetype, _access: &mut Access<ArchetypeComponentId>) {
+                let ($($name,)*) = &self.0;
+                $(
+                    if $name.matches_archetype(_archetype) {
+                        $name.update_archetype_component_access(_archetype, _access);
+                    }
+                )*
+            }
+
+            fn matches_archetype(&self, _archetype: &Archetype) -> bool {
+                let ($($name,)*) = &self.0;
+                false $(|| $name.matches_archetype(_archetype))*
+            }
+
+            fn matches_table(&self, _table: &Table) -> bool {
+                let ($($name,)*) = &self.0;
+                false $(|| $name.matches_table(_table))*
+            }
+        }
+    };
+}
+
+all_tuples!(impl_any_of_fetch, 0, 15, F, S);
+
+/// [`WorldQuery`] that tracks changes and additions for component `T`.
+///
+/// Wraps a [`Component`] to track whether the component changed for the corresponding entities in
+/// a query since the last time the system that includes these queries ran.
+///
+/// If you only care about entities that changed or that got added use the
+/// [`Changed`](crate::query::Changed) and [`Added`](crate::query::Added) filters instead.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::query::ChangeTrackers;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::system::Query;
+/// #
+/// # #[derive(Component, Debug)]
+/// # struct Name {};
+/// # #[derive(Component)]
+/// # struct Transform {};
+/// #
+/// fn print_moving_objects_system(query: Query<(&Name, ChangeTrackers<Transform>)>) {
+///     for (name, tracker) in query.iter() {
+///         if tracker.is_changed() {
+///             println!("Entity moved: {:?}", name);
+///         } else {
+///             println!("Entity stood still: {:?}", name);
+///         }
+///     }
+/// }
+/// # print_moving_objects_system.system();
+/// ```
+#[derive(Clone)]
+pub struct ChangeTrackers<T: Component> {
+    pub(crate) component_ticks: ComponentTicks,
+    pub(crate) last_change_tick: Tick,
+    pub(crate) change_tick: Tick,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> std::fmt::Debug for ChangeTrackers<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeTrackers")
+            .field("component_ticks", &self.component_ticks)
+            .field("last_change_tick", &self.last_change_tick)
+            .field("change_tick", &self.change_tick)
+            .finish()
+    }
+}
+
+impl<T: Component> ChangeTrackers<T> {
+    /// Returns true if this component has been added since the last execution of this system.
+    pub fn is_added(&self) -> bool {
+        self.component_ticks
+            .is_added(self.last_change_tick, self.change_tick)
+    }
+
+    /// Returns true if this component has been changed since the last execution of this system.
+    pub fn is_changed(&self) -> bool {
+        self.component_ticks
+            .is_changed(self.last_change_tick, self.change_tick)
+    }
+}
+
+impl<T: Component> WorldQuery for ChangeTrackers<T> {
+    type Fetch = ChangeTrackersFetch<T>;
+    type State = ChangeTrackersState<T>;
+    type ReadOnlyFetch = ChangeTrackersFetch<T>;
+}
+
+/// The [`FetchState`] of [`ChangeTrackers`].
+pub struct ChangeTrackersState<T> {
+    component_id: ComponentId,
+    marker: PhantomData<T>,
+}
+
+// SAFETY: component access and archetype component access are properly updated to reflect that T is
+// read
+unsafe impl<T: Component> FetchState for ChangeTrackersState<T> {
+    fn init(world: &mut World) -> Self {
+        assert_has_change_ticks::<T>("ChangeTrackers");
+        let component_id = world.init_component::<T>();
+        Self {
+            component_id,
+            marker: PhantomData,
+        }
+    }
+
+    fn try_init(world: &World) -> Option<Self> {
+        assert_has_change_ticks::<T>("ChangeTrackers");
+        Some(Self {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
+    fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
+        if access.access().has_write(self.component_id) {
+            panic!("ChangeTrackers<{}> conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.",
+                std::any::type_name::<T>());
+        }
+        access.add_read(self.component_id)
+    }
+
+    fn update_archetype_component_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        if let Some(archetype_component_id) =
+            archetype.get_archetype_component_id(self.component_id)
+        {
             access.add_read(archetype_component_id);
         }
     }
@@ -899,8 +1481,8 @@ pub struct ChangeTrackersFetch<T> {
     entities: *const Entity,
     sparse_set: *const ComponentSparseSet,
     marker: PhantomData<T>,
-    last_change_tick: u32,
-    change_tick: u32,
+    last_change_tick: Tick,
+    change_tick: Tick,
 }
 
 impl<T> Clone for ChangeTrackersFetch<T> {
@@ -927,15 +1509,15 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ChangeTrackersFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
     unsafe fn init(
         world: &World,
         state: &Self::State,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         let mut value = Self {
             table_ticks: ptr::null::<ComponentTicks>(),
@@ -972,6 +1554,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ChangeTrackersFetch<T> {
                 self.table_ticks = column.get_ticks_const_ptr();
             }
             StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+            StorageType::Tag => {}
         }
     }
 
@@ -1004,6 +1587,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for ChangeTrackersFetch<T> {
                     change_tick: self.change_tick,
                 }
             }
+            // rejected by the state, tags have no change ticks
+            StorageType::Tag => unreachable!(),
         }
     }
 
@@ -1026,7 +1611,7 @@ macro_rules! impl_tuple_fetch {
             type State = ($($name::State,)*);
 
             #[allow(clippy::unused_unit)]
-            unsafe fn init(_world: &World, state: &Self::State, _last_change_tick: u32, _change_tick: u32) -> Self {
+            unsafe fn init(_world: &World, state: &Self::State, _last_change_tick: Tick, _change_tick: Tick) -> Self {
                 let ($($name,)*) = state;
                 ($($name::init(_world, $name, _last_change_tick, _change_tick),)*)
             }
@@ -1070,6 +1655,10 @@ macro_rules! impl_tuple_fetch {
                 ($($name::init(_world),)*)
             }
 
+            fn try_init(_world: &World) -> Option<Self> {
+                Some(($($name::try_init(_world)?,)*))
+            }
+
             fn update_component_access(&self, _access: &mut FilteredAccess<ComponentId>) {
                 let ($($name,)*) = self;
                 $($name.update_component_access(_access);)*
@@ -1105,6 +1694,469 @@ macro_rules! impl_tuple_fetch {
 
 all_tuples!(impl_tuple_fetch, 0, 15, F, S);
 
+/// A [`Fetch`] that can return the items of a range of consecutive table rows as a single
+/// chunk, such as a slice of components.
+///
+/// Components stored in tables are fetched as slices of their columns. Sparse set components are
+/// not laid out contiguously, so they are gathered into a `Vec` per chunk instead, and tags are
+/// fetched as slices of zero-sized values, see [`ChunkStorage`]. See
+/// [`QueryState::for_each_chunk`](crate::query::QueryState::for_each_chunk).
+///
+/// # Safety
+///
+/// [`ChunkFetch::table_chunk`] must access exactly the data [`Fetch::table_fetch`] or
+/// [`Fetch::archetype_fetch`] would access for the entities of every row in `rows`.
+pub unsafe trait ChunkFetch<'world, 'state>: Fetch<'world, 'state> {
+    type Chunk;
+
+    /// Adjusts internal state to fetch chunks of the rows of `table`, which is either the current
+    /// table or the table of the current archetype.
+    ///
+    /// # Safety
+    ///
+    /// `table` must be from the [`World`] [`Fetch::init`] was called on. `state` must be the
+    /// [`Self::State`] this was initialized with.
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table);
+
+    /// Fetch [`Self::Chunk`] for the given `rows` of the current [`Table`].
+    ///
+    /// # Safety
+    ///
+    /// Must always be called _after_ [`ChunkFetch::set_chunk_table`]. `rows` must be in the range
+    /// of the current table.
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk;
+}
+
+/// The storage of a component that can be fetched in chunks, which decides the chunk types.
+///
+/// Components stored in tables are fetched as slices, while the components of sparse set
+/// components are gathered for the entities of each chunk. Tags are zero-sized, so a chunk of
+/// tags is a slice that takes no memory. Tags have no change ticks and can't be queried mutably,
+/// so `&mut T` and `ChangeTrackers<T>` queries of tags panic when they are created, as they do
+/// outside of chunk iteration:
+///
+/// | Query               | [`TableStorage`]            | [`SparseStorage`]         | [`TagStorage`] |
+/// |---------------------|-----------------------------|---------------------------|----------------|
+/// | `&T`                | `&[T]`                      | `Vec<&T>`                 | `&[T]`         |
+/// | `&mut T`            | [`ChunkMut<T>`]             | `Vec<Mut<T>>`             | panics         |
+/// | `ChangeTrackers<T>` | [`ChangeTrackersChunk<T>`]  | `Vec<ChangeTrackers<T>>`  | panics         |
+pub trait ChunkStorage<'w, T: Component>: ComponentStorage {
+    /// The chunk of `&T` queries.
+    type Chunk;
+    /// The chunk of `&mut T` queries.
+    type ChunkMut;
+    /// The chunk of [`ChangeTrackers<T>`] queries.
+    type ChangeTrackersChunk;
+
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// `parts` must point to the current table or sparse set, and `rows` must be in its range.
+    unsafe fn chunk(parts: ChunkParts<T>, rows: Range<usize>) -> Self::Chunk;
+
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// See [`ChunkStorage::chunk`]. `parts` must have unique access to the components and ticks.
+    unsafe fn chunk_mut(parts: ChunkParts<T>, rows: Range<usize>) -> Self::ChunkMut;
+
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// See [`ChunkStorage::chunk`].
+    unsafe fn change_trackers_chunk(
+        parts: ChunkParts<T>,
+        rows: Range<usize>,
+    ) -> Self::ChangeTrackersChunk;
+}
+
+/// The pointers of a component [`Fetch`] that its chunks are created from.
+#[doc(hidden)]
+pub struct ChunkParts<T> {
+    table_components: NonNull<T>,
+    table_ticks: *const UnsafeCell<ComponentTicks>,
+    /// The entities of the current table, by row.
+    entities: *const Entity,
+    sparse_set: *const ComponentSparseSet,
+    last_change_tick: Tick,
+    change_tick: Tick,
+}
+
+impl<T> ChunkParts<T> {
+    /// # Safety
+    ///
+    /// `row` must be in the range of the current table.
+    #[inline]
+    unsafe fn sparse_get_with_ticks(&self, row: usize) -> (*mut u8, *mut ComponentTicks) {
+        let entity = *self.entities.add(row);
+        (*self.sparse_set).get_with_ticks(entity).unwrap()
+    }
+}
+
+impl<'w, T: Component> ChunkStorage<'w, T> for TableStorage {
+    type Chunk = &'w [T];
+    type ChunkMut = ChunkMut<'w, T>;
+    type ChangeTrackersChunk = ChangeTrackersChunk<'w, T>;
+
+    #[inline]
+    unsafe fn chunk(parts: ChunkParts<T>, rows: Range<usize>) -> Self::Chunk {
+        std::slice::from_raw_parts(parts.table_components.as_ptr().add(rows.start), rows.len())
+    }
+
+    #[inline]
+    unsafe fn chunk_mut(parts: ChunkParts<T>, rows: Range<usize>) -> Self::ChunkMut {
+        ChunkMut {
+            value: std::slice::from_raw_parts_mut(
+                parts.table_components.as_ptr().add(rows.start),
+                rows.len(),
+            ),
+            ticks: std::slice::from_raw_parts(parts.table_ticks.add(rows.start), rows.len()),
+            last_change_tick: parts.last_change_tick,
+            change_tick: parts.change_tick,
+        }
+    }
+
+    #[inline]
+    unsafe fn change_trackers_chunk(
+        parts: ChunkParts<T>,
+        rows: Range<usize>,
+    ) -> Self::ChangeTrackersChunk {
+        ChangeTrackersChunk {
+            ticks: std::slice::from_raw_parts(
+                parts.table_ticks.add(rows.start).cast::<ComponentTicks>(),
+                rows.len(),
+            ),
+            last_change_tick: parts.last_change_tick,
+            change_tick: parts.change_tick,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'w, T: Component> ChunkStorage<'w, T> for SparseStorage {
+    type Chunk = Vec<&'w T>;
+    type ChunkMut = Vec<Mut<'w, T>>;
+    type ChangeTrackersChunk = Vec<ChangeTrackers<T>>;
+
+    #[inline]
+    unsafe fn chunk(parts: ChunkParts<T>, rows: Range<usize>) -> Self::Chunk {
+        rows.map(|row| &*parts.sparse_get_with_ticks(row).0.cast::<T>())
+            .collect()
+    }
+
+    #[inline]
+    unsafe fn chunk_mut(parts: ChunkParts<T>, rows: Range<usize>) -> Self::ChunkMut {
+        rows.map(|row| {
+            let (component, component_ticks) = parts.sparse_get_with_ticks(row);
+            Mut {
+                value: &mut *component.cast::<T>(),
+                ticks: Ticks {
+                    component_ticks: &mut *component_ticks,
+                    change_tick: parts.change_tick,
+                    last_change_tick: parts.last_change_tick,
+                },
+            }
+        })
+        .collect()
+    }
+
+    #[inline]
+    unsafe fn change_trackers_chunk(
+        parts: ChunkParts<T>,
+        rows: Range<usize>,
+    ) -> Self::ChangeTrackersChunk {
+        rows.map(|row| ChangeTrackers {
+            component_ticks: (*parts.sparse_get_with_ticks(row).1).clone(),
+            marker: PhantomData,
+            last_change_tick: parts.last_change_tick,
+            change_tick: parts.change_tick,
+        })
+        .collect()
+    }
+}
+
+impl<'w, T: Component> ChunkStorage<'w, T> for TagStorage {
+    type Chunk = &'w [T];
+    type ChunkMut = ChunkMut<'w, T>;
+    type ChangeTrackersChunk = ChangeTrackersChunk<'w, T>;
+
+    #[inline]
+    unsafe fn chunk(_parts: ChunkParts<T>, rows: Range<usize>) -> Self::Chunk {
+        // tags are zero-sized, so any aligned pointer points to their values
+        std::slice::from_raw_parts(NonNull::dangling().as_ptr(), rows.len())
+    }
+
+    unsafe fn chunk_mut(_parts: ChunkParts<T>, _rows: Range<usize>) -> Self::ChunkMut {
+        unreachable!("`&mut T` queries of tags panic when their state is initialized")
+    }
+
+    unsafe fn change_trackers_chunk(
+        _parts: ChunkParts<T>,
+        _rows: Range<usize>,
+    ) -> Self::ChangeTrackersChunk {
+        unreachable!("`ChangeTrackers<T>` queries of tags panic when their state is initialized")
+    }
+}
+
+/// Sets up a component fetch to create chunks of the rows of `table`: table components are read
+/// from their column, sparse set components are looked up by the entities of the table. Tags
+/// need no setup.
+macro_rules! set_component_chunk_table {
+    ($fetch: ident, $state: ident, $table: ident) => {
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => $fetch.set_table($state, $table),
+            StorageType::SparseSet | StorageType::Tag => {
+                $fetch.entities = $table.entities().as_ptr()
+            }
+        }
+    };
+}
+
+// SAFETY: reads the same entities as `table_fetch`
+unsafe impl<'w, 's> ChunkFetch<'w, 's> for EntityFetch {
+    type Chunk = &'w [Entity];
+
+    #[inline]
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table) {
+        self.set_table(state, table);
+    }
+
+    #[inline]
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk {
+        std::slice::from_raw_parts(self.entities.add(rows.start), rows.len())
+    }
+}
+
+// SAFETY: reads the same components as `table_fetch` and `archetype_fetch`
+unsafe impl<'w, 's, T: Component> ChunkFetch<'w, 's> for ReadFetch<T>
+where
+    T::Storage: ChunkStorage<'w, T>,
+{
+    type Chunk = <T::Storage as ChunkStorage<'w, T>>::Chunk;
+
+    #[inline]
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table) {
+        set_component_chunk_table!(self, state, table);
+    }
+
+    #[inline]
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk {
+        let parts = ChunkParts {
+            table_components: self.table_components,
+            table_ticks: ptr::null(),
+            entities: self.entities,
+            sparse_set: self.sparse_set,
+            last_change_tick: 0,
+            change_tick: 0,
+        };
+        T::Storage::chunk(parts, rows)
+    }
+}
+
+// SAFETY: reads the same components as `table_fetch` and `archetype_fetch`
+unsafe impl<'w, 's, T: Component> ChunkFetch<'w, 's> for ReadOnlyWriteFetch<T>
+where
+    T::Storage: ChunkStorage<'w, T>,
+{
+    type Chunk = <T::Storage as ChunkStorage<'w, T>>::Chunk;
+
+    #[inline]
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table) {
+        set_component_chunk_table!(self, state, table);
+    }
+
+    #[inline]
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk {
+        let parts = ChunkParts {
+            table_components: self.table_components,
+            table_ticks: ptr::null(),
+            entities: self.entities,
+            sparse_set: self.sparse_set,
+            last_change_tick: 0,
+            change_tick: 0,
+        };
+        T::Storage::chunk(parts, rows)
+    }
+}
+
+// SAFETY: writes the same components and ticks as `table_fetch` and `archetype_fetch`
+unsafe impl<'w, 's, T: Component> ChunkFetch<'w, 's> for WriteFetch<T>
+where
+    T::Storage: ChunkStorage<'w, T>,
+{
+    type Chunk = <T::Storage as ChunkStorage<'w, T>>::ChunkMut;
+
+    #[inline]
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table) {
+        set_component_chunk_table!(self, state, table);
+    }
+
+    #[inline]
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk {
+        let parts = ChunkParts {
+            table_components: self.table_components,
+            table_ticks: self.table_ticks,
+            entities: self.entities,
+            sparse_set: self.sparse_set,
+            last_change_tick: self.last_change_tick,
+            change_tick: self.change_tick,
+        };
+        T::Storage::chunk_mut(parts, rows)
+    }
+}
+
+// SAFETY: reads the same ticks as `table_fetch` and `archetype_fetch`
+unsafe impl<'w, 's, T: Component> ChunkFetch<'w, 's> for ChangeTrackersFetch<T>
+where
+    T::Storage: ChunkStorage<'w, T>,
+{
+    type Chunk = <T::Storage as ChunkStorage<'w, T>>::ChangeTrackersChunk;
+
+    #[inline]
+    unsafe fn set_chunk_table(&mut self, state: &Self::State, table: &Table) {
+        set_component_chunk_table!(self, state, table);
+    }
+
+    #[inline]
+    unsafe fn table_chunk(&mut self, rows: Range<usize>) -> Self::Chunk {
+        let parts = ChunkParts {
+            table_components: NonNull::dangling(),
+            table_ticks: self.table_ticks.cast::<UnsafeCell<ComponentTicks>>(),
+            entities: self.entities,
+            sparse_set: self.sparse_set,
+            last_change_tick: self.last_change_tick,
+            change_tick: self.change_tick,
+        };
+        T::Storage::change_trackers_chunk(parts, rows)
+    }
+}
+
+/// A mutable slice of components returned by chunked iteration of `&mut T` queries.
+///
+/// Mutably dereferencing the chunk marks every component in it as changed.
+pub struct ChunkMut<'a, T> {
+    value: &'a mut [T],
+    ticks: &'a [UnsafeCell<ComponentTicks>],
+    last_change_tick: Tick,
+    change_tick: Tick,
+}
+
+impl<'a, T> ChunkMut<'a, T> {
+    /// Returns the change ticks of the components in this chunk.
+    pub fn ticks(&self) -> &[ComponentTicks] {
+        // SAFE: the ticks are only written through `&mut self`
+        unsafe { &*(self.ticks as *const [UnsafeCell<ComponentTicks>] as *const [ComponentTicks]) }
+    }
+
+    /// Returns true if the component at `index` was added after the system last ran.
+    pub fn is_added(&self, index: usize) -> bool {
+        self.ticks()[index].is_added(self.last_change_tick, self.change_tick)
+    }
+
+    /// Returns true if the component at `index` was added or mutably dereferenced after the
+    /// system last ran.
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.ticks()[index].is_changed(self.last_change_tick, self.change_tick)
+    }
+
+    /// Returns a mutable reference to the component at `index`, only marking that component as
+    /// changed.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let value = self.value.get_mut(index)?;
+        // SAFE: the ticks of this chunk are only accessed through `&mut self`
+        unsafe { (*self.ticks[index].get()).set_changed(self.change_tick) };
+        Some(value)
+    }
+}
+
+impl<'a, T> Deref for ChunkMut<'a, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<'a, T> DerefMut for ChunkMut<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        for ticks in self.ticks {
+            // SAFE: the ticks of this chunk are only accessed through `&mut self`
+            unsafe { (*ticks.get()).set_changed(self.change_tick) };
+        }
+        self.value
+    }
+}
+
+impl<'a, T: std::fmt::Debug> std::fmt::Debug for ChunkMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ChunkMut").field(&self.value).finish()
+    }
+}
+
+/// The change ticks of a chunk of `T` components, returned by chunked iteration of
+/// [`ChangeTrackers<T>`] queries.
+pub struct ChangeTrackersChunk<'a, T> {
+    ticks: &'a [ComponentTicks],
+    last_change_tick: Tick,
+    change_tick: Tick,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> ChangeTrackersChunk<'a, T> {
+    /// Returns the change ticks of the components in this chunk.
+    pub fn ticks(&self) -> &'a [ComponentTicks] {
+        self.ticks
+    }
+
+    /// Returns the number of components in this chunk.
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Returns true if this chunk is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+
+    /// Returns true if the component at `index` was added after the system last ran.
+    pub fn is_added(&self, index: usize) -> bool {
+        self.ticks[index].is_added(self.last_change_tick, self.change_tick)
+    }
+
+    /// Returns true if the component at `index` was added or changed after the system last ran.
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.ticks[index].is_changed(self.last_change_tick, self.change_tick)
+    }
+}
+
+macro_rules! impl_tuple_chunk_fetch {
+    ($(($name: ident, $state: ident)),*) => {
+        // SAFETY: each item in the tuple fetches the same data as its `table_fetch`
+        #[allow(non_snake_case)]
+        unsafe impl<'w, 's, $($name: ChunkFetch<'w, 's>),*> ChunkFetch<'w, 's> for ($($name,)*) {
+            type Chunk = ($($name::Chunk,)*);
+
+            #[inline]
+            unsafe fn set_chunk_table(&mut self, _state: &Self::State, _table: &Table) {
+                let ($($name,)*) = self;
+                let ($($state,)*) = _state;
+                $($name.set_chunk_table($state, _table);)*
+            }
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            unsafe fn table_chunk(&mut self, _rows: Range<usize>) -> Self::Chunk {
+                let ($($name,)*) = self;
+                ($($name.table_chunk(_rows.clone()),)*)
+            }
+        }
+    };
+}
+
+all_tuples!(impl_tuple_chunk_fetch, 0, 15, F, S);
+
 /// [`Fetch`] that does not actually fetch anything
 ///
 /// Mostly useful when something is generic over the Fetch and you don't want to fetch as you will discard the result
@@ -1122,8 +2174,8 @@ impl<'w, 's, State: FetchState> Fetch<'w, 's> for NopFetch<State> {
     unsafe fn init(
         _world: &World,
         _state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         Self { state: PhantomData }
     }
@@ -1,8 +1,11 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId},
-    component::{Component, ComponentId, ComponentStorage, ComponentTicks, StorageType},
+    component::{Component, ComponentId, ComponentStorage, ComponentTicks, StorageType, Tick},
     entity::Entity,
-    query::{Access, Fetch, FetchState, FilteredAccess, ReadOnlyFetch, WorldQuery},
+    query::{
+        fetch::assert_has_change_ticks, Access, Fetch, FetchState, FilteredAccess, ReadOnlyFetch,
+        WorldQuery,
+    },
     storage::{ComponentSparseSet, Table, Tables},
     world::World,
 };
@@ -96,6 +99,13 @@ unsafe impl<T: Component> FetchState for WithState<T> {
         }
     }
 
+    fn try_init(world: &World) -> Option<Self> {
+        Some(Self {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
     #[inline]
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
         access.add_with(self.component_id);
@@ -125,8 +135,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WithFetch<T> {
     unsafe fn init(
         _world: &World,
         _state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         Self {
             marker: PhantomData,
@@ -136,7 +146,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WithFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
@@ -219,6 +229,13 @@ unsafe impl<T: Component> FetchState for WithoutState<T> {
         }
     }
 
+    fn try_init(world: &World) -> Option<Self> {
+        Some(Self {
+            component_id: world.components().component_id::<T>()?,
+            marker: PhantomData,
+        })
+    }
+
     #[inline]
     fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
         access.add_without(self.component_id);
@@ -248,8 +265,8 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WithoutFetch<T> {
     unsafe fn init(
         _world: &World,
         _state: &Self::State,
-        _last_change_tick: u32,
-        _change_tick: u32,
+        _last_change_tick: Tick,
+        _change_tick: Tick,
     ) -> Self {
         Self {
             marker: PhantomData,
@@ -259,7 +276,7 @@ impl<'w, 's, T: Component> Fetch<'w, 's> for WithoutFetch<T> {
     const IS_DENSE: bool = {
         match T::Storage::STORAGE_TYPE {
             StorageType::Table => true,
-            StorageType::SparseSet => false,
+            StorageType::SparseSet | StorageType::Tag => false,
         }
     };
 
@@ -362,7 +379,7 @@ macro_rules! impl_query_filter_tuple {
             type State = Or<($(<$filter as Fetch<'w, 's>>::State,)*)>;
             type Item = bool;
 
-            unsafe fn init(world: &World, state: &Self::State, last_change_tick: u32, change_tick: u32) -> Self {
+            unsafe fn init(world: &World, state: &Self::State, last_change_tick: Tick, change_tick: Tick) -> Self {
                 let ($($filter,)*) = &state.0;
                 Or(($(OrFetch {
                     fetch: $filter::init(world, $filter, last_change_tick, change_tick),
@@ -417,6 +434,10 @@ macro_rules! impl_query_filter_tuple {
                 Or(($($filter::init(world),)*))
             }
 
+            fn try_init(world: &World) -> Option<Self> {
+                Some(Or(($($filter::try_init(world)?,)*)))
+            }
+
             fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
                 let ($($filter,)*) = &self.0;
                 $($filter.update_component_access(access);)*
@@ -442,6 +463,133 @@ macro_rules! impl_query_filter_tuple {
 
 all_tuples!(impl_query_filter_tuple, 0, 15, F, S);
 
+/// A predicate over the read-only query results of `Q`, used by the [`Matches`] filter.
+///
+/// Predicates are types rather than closures, so that they can be named in the type of a
+/// [`Query`](crate::system::Query).
+pub trait QueryPredicate<Q: WorldQuery>: Send + Sync + 'static {
+    /// Returns `true` if the entity with the query result `item` should be matched.
+    fn test(item: <Q::Fetch as Fetch<'_, '_>>::Item) -> bool;
+}
+
+/// Filter that selects entities whose read-only query results of `Q` satisfy the predicate `P`.
+///
+/// The predicate is evaluated per entity while iterating, and is also respected by methods
+/// such as [`Query::get`](crate::system::Query::get) and
+/// [`Query::is_empty`](crate::system::Query::is_empty). Entities that don't match `Q` are
+/// filtered out as well.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::query::{Matches, QueryPredicate};
+///
+/// #[derive(Component)]
+/// struct Health(f32);
+/// # #[derive(Component)]
+/// # struct Name(String);
+///
+/// struct LowHealth;
+///
+/// impl QueryPredicate<&Health> for LowHealth {
+///     fn test(health: &Health) -> bool {
+///         health.0 < 10.0
+///     }
+/// }
+///
+/// fn warn_low_health_system(query: Query<&Name, Matches<&Health, LowHealth>>) {
+///     for name in query.iter() {
+///         println!("{} is about to die!", name.0);
+///     }
+/// }
+/// # warn_low_health_system.system();
+/// ```
+pub struct Matches<Q, P>(PhantomData<(Q, P)>);
+
+impl<Q: WorldQuery, P: QueryPredicate<Q>> WorldQuery for Matches<Q, P>
+where
+    Q::Fetch: ReadOnlyFetch,
+{
+    type Fetch = MatchesFetch<Q, P>;
+    type State = Q::State;
+    type ReadOnlyFetch = MatchesFetch<Q, P>;
+}
+
+/// The [`Fetch`] of [`Matches`].
+pub struct MatchesFetch<Q: WorldQuery, P> {
+    fetch: Q::Fetch,
+    marker: PhantomData<P>,
+}
+
+impl<Q: WorldQuery, P> Clone for MatchesFetch<Q, P>
+where
+    Q::Fetch: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            fetch: self.fetch.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// SAFETY: `Q::Fetch` is read only
+unsafe impl<Q: WorldQuery, P> ReadOnlyFetch for MatchesFetch<Q, P> where Q::Fetch: ReadOnlyFetch {}
+
+impl<'w, 's, Q: WorldQuery, P: QueryPredicate<Q>> Fetch<'w, 's> for MatchesFetch<Q, P>
+where
+    Q::Fetch: ReadOnlyFetch,
+{
+    type Item = bool;
+    type State = Q::State;
+
+    const IS_DENSE: bool = <Q::Fetch as Fetch<'w, 's>>::IS_DENSE;
+
+    unsafe fn init(
+        world: &World,
+        state: &Self::State,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        Self {
+            fetch: <Q::Fetch as Fetch<'w, 's>>::init(world, state, last_change_tick, change_tick),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    unsafe fn set_archetype(
+        &mut self,
+        state: &Self::State,
+        archetype: &Archetype,
+        tables: &Tables,
+    ) {
+        <Q::Fetch as Fetch<'w, 's>>::set_archetype(&mut self.fetch, state, archetype, tables);
+    }
+
+    #[inline]
+    unsafe fn set_table(&mut self, state: &Self::State, table: &Table) {
+        <Q::Fetch as Fetch<'w, 's>>::set_table(&mut self.fetch, state, table);
+    }
+
+    #[inline]
+    unsafe fn archetype_fetch(&mut self, archetype_index: usize) -> bool {
+        P::test(<Q::Fetch as Fetch<'w, 's>>::archetype_fetch(
+            &mut self.fetch,
+            archetype_index,
+        ))
+    }
+
+    #[inline]
+    unsafe fn table_fetch(&mut self, table_row: usize) -> bool {
+        P::test(<Q::Fetch as Fetch<'w, 's>>::table_fetch(
+            &mut self.fetch,
+            table_row,
+        ))
+    }
+}
+
 macro_rules! impl_tick_filter {
     (
         $(#[$meta:meta])*
@@ -462,8 +610,8 @@ macro_rules! impl_tick_filter {
             marker: PhantomData<T>,
             entities: *const Entity,
             sparse_set: *const ComponentSparseSet,
-            last_change_tick: u32,
-            change_tick: u32,
+            last_change_tick: Tick,
+            change_tick: Tick,
         }
 
         $(#[$state_meta])*
@@ -481,12 +629,21 @@ macro_rules! impl_tick_filter {
         // SAFETY: this reads the T component. archetype component access and component access are updated to reflect that
         unsafe impl<T: Component> FetchState for $state_name<T> {
             fn init(world: &mut World) -> Self {
+                assert_has_change_ticks::<T>(stringify!($name));
                 Self {
                     component_id: world.init_component::<T>(),
                     marker: PhantomData,
                 }
             }
 
+            fn try_init(world: &World) -> Option<Self> {
+                assert_has_change_ticks::<T>(stringify!($name));
+                Some(Self {
+                    component_id: world.components().component_id::<T>()?,
+                    marker: PhantomData,
+                })
+            }
+
             #[inline]
             fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>) {
                 if access.access().has_write(self.component_id) {
@@ -520,7 +677,7 @@ macro_rules! impl_tick_filter {
             type State = $state_name<T>;
             type Item = bool;
 
-            unsafe fn init(world: &World, state: &Self::State, last_change_tick: u32, change_tick: u32) -> Self {
+            unsafe fn init(world: &World, state: &Self::State, last_change_tick: Tick, change_tick: Tick) -> Self {
                 let mut value = Self {
                     table_ticks: ptr::null::<UnsafeCell<ComponentTicks>>(),
                     entities: ptr::null::<Entity>(),
@@ -542,7 +699,7 @@ macro_rules! impl_tick_filter {
             const IS_DENSE: bool = {
                 match T::Storage::STORAGE_TYPE {
                     StorageType::Table => true,
-                    StorageType::SparseSet => false,
+                    StorageType::SparseSet | StorageType::Tag => false,
                 }
             };
 
@@ -562,6 +719,7 @@ macro_rules! impl_tick_filter {
                             .get_ticks_ptr();
                     }
                     StorageType::SparseSet => self.entities = archetype.entities().as_ptr(),
+                    StorageType::Tag => {}
                 }
             }
 
@@ -580,6 +738,8 @@ macro_rules! impl_tick_filter {
                         let ticks = (&*self.sparse_set).get_ticks(entity).cloned().unwrap();
                         $is_detected(&ticks, self.last_change_tick, self.change_tick)
                     }
+                    // rejected by the state, tags have no change ticks
+                    StorageType::Tag => unreachable!(),
                 }
             }
         }
@@ -1,6 +1,8 @@
 use crate::{
     archetype::{ArchetypeId, Archetypes},
-    query::{Fetch, FilterFetch, QueryState, ReadOnlyFetch, WorldQuery},
+    component::Tick,
+    entity::{Entities, Entity, EntityReference},
+    query::{ChunkFetch, Fetch, FetchState, FilterFetch, QueryState, ReadOnlyFetch, WorldQuery},
     storage::{TableId, Tables},
     world::World,
 };
@@ -39,8 +41,8 @@ where
     pub(crate) unsafe fn new(
         world: &'w World,
         query_state: &'s QueryState<Q, F>,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         let fetch = QF::init(
             world,
@@ -83,7 +85,7 @@ where
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
-            if QF::IS_DENSE && F::Fetch::IS_DENSE {
+            if QF::IS_DENSE && F::Fetch::IS_DENSE && self.query_state.is_dense {
                 loop {
                     if self.current_index == self.current_len {
                         let table_id = self.table_id_iter.next()?;
@@ -153,6 +155,534 @@ where
     }
 }
 
+/// An [`Iterator`] over chunks of query results, where each chunk covers a run of consecutive
+/// rows of a table.
+///
+/// If the query is dense, there is one chunk per table row range that passes the filter.
+/// Otherwise the matched archetypes are walked one at a time, and the rows of each archetype are
+/// gathered into runs of consecutive table rows.
+///
+/// This struct is created by the [`Query::iter_chunks`](crate::system::Query::iter_chunks) and
+/// [`Query::iter_chunks_mut`](crate::system::Query::iter_chunks_mut) methods.
+pub struct QueryChunkIter<'w, 's, Q: WorldQuery, QF, F: WorldQuery>
+where
+    QF: ChunkFetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    tables: &'w Tables,
+    archetypes: &'w Archetypes,
+    query_state: &'s QueryState<Q, F>,
+    table_id_iter: std::slice::Iter<'s, TableId>,
+    archetype_id_iter: std::slice::Iter<'s, ArchetypeId>,
+    entity_table_rows: &'w [usize],
+    fetch: QF,
+    filter: F::Fetch,
+    current_len: usize,
+    current_index: usize,
+}
+
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery> QueryChunkIter<'w, 's, Q, QF, F>
+where
+    QF: ChunkFetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    /// # Safety
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `query_state.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn new(
+        world: &'w World,
+        query_state: &'s QueryState<Q, F>,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        let fetch = QF::init(
+            world,
+            &query_state.fetch_state,
+            last_change_tick,
+            change_tick,
+        );
+        let filter = <F::Fetch as Fetch>::init(
+            world,
+            &query_state.filter_state,
+            last_change_tick,
+            change_tick,
+        );
+
+        QueryChunkIter {
+            tables: &world.storages().tables,
+            archetypes: &world.archetypes,
+            query_state,
+            table_id_iter: query_state.matched_table_ids.iter(),
+            archetype_id_iter: query_state.matched_archetype_ids.iter(),
+            entity_table_rows: &[],
+            fetch,
+            filter,
+            current_len: 0,
+            current_index: 0,
+        }
+    }
+
+    /// Returns the table row of `index` in the current table or archetype, if it passes the
+    /// filter.
+    ///
+    /// # Safety
+    /// `index` must be in the range of the current table or archetype.
+    #[inline]
+    unsafe fn filtered_table_row(&mut self, index: usize) -> Option<usize> {
+        if QF::IS_DENSE && F::Fetch::IS_DENSE && self.query_state.is_dense {
+            self.filter.table_filter_fetch(index).then_some(index)
+        } else {
+            self.filter
+                .archetype_filter_fetch(index)
+                .then(|| self.entity_table_rows[index])
+        }
+    }
+}
+
+// Every chunk covers rows that no other chunk covers, so chunks of mutable queries never alias.
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery> Iterator for QueryChunkIter<'w, 's, Q, QF, F>
+where
+    QF: ChunkFetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    type Item = QF::Chunk;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            loop {
+                if self.current_index == self.current_len {
+                    if QF::IS_DENSE && F::Fetch::IS_DENSE && self.query_state.is_dense {
+                        let table_id = self.table_id_iter.next()?;
+                        let table = &self.tables[*table_id];
+                        self.fetch
+                            .set_chunk_table(&self.query_state.fetch_state, table);
+                        self.filter.set_table(&self.query_state.filter_state, table);
+                        self.current_len = table.len();
+                    } else {
+                        let archetype_id = self.archetype_id_iter.next()?;
+                        let archetype = &self.archetypes[*archetype_id];
+                        // chunks are made of consecutive rows of the archetype's table
+                        let table = &self.tables[archetype.table_id()];
+                        self.fetch
+                            .set_chunk_table(&self.query_state.fetch_state, table);
+                        self.filter.set_archetype(
+                            &self.query_state.filter_state,
+                            archetype,
+                            self.tables,
+                        );
+                        self.entity_table_rows = archetype.entity_table_rows();
+                        self.current_len = archetype.len();
+                    }
+                    self.current_index = 0;
+                    continue;
+                }
+
+                let start = match self.filtered_table_row(self.current_index) {
+                    Some(row) => row,
+                    None => {
+                        self.current_index += 1;
+                        continue;
+                    }
+                };
+                let mut len = 1;
+                self.current_index += 1;
+                while self.current_index < self.current_len
+                    && self.filtered_table_row(self.current_index) == Some(start + len)
+                {
+                    len += 1;
+                    self.current_index += 1;
+                }
+
+                return Some(self.fetch.table_chunk(start..start + len));
+            }
+        }
+    }
+}
+
+/// An [`Iterator`] over the query results of a list of [`Entity`]s.
+///
+/// Entities that don't exist or don't match the query are skipped.
+///
+/// This struct is created by the [`Query::iter_many`](crate::system::Query::iter_many) method.
+pub struct QueryManyIter<'w, 's, Q: WorldQuery, QF, F: WorldQuery, I: Iterator<Item = Entity>>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    entity_iter: I,
+    entities: &'w Entities,
+    tables: &'w Tables,
+    archetypes: &'w Archetypes,
+    query_state: &'s QueryState<Q, F>,
+    fetch: QF,
+    filter: F::Fetch,
+}
+
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery, I: Iterator<Item = Entity>>
+    QueryManyIter<'w, 's, Q, QF, F, I>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    /// # Safety
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `query_state.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn new<EntityList: IntoIterator<IntoIter = I>>(
+        world: &'w World,
+        query_state: &'s QueryState<Q, F>,
+        entity_list: EntityList,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        let fetch = QF::init(
+            world,
+            &query_state.fetch_state,
+            last_change_tick,
+            change_tick,
+        );
+        let filter = <F::Fetch as Fetch>::init(
+            world,
+            &query_state.filter_state,
+            last_change_tick,
+            change_tick,
+        );
+
+        QueryManyIter {
+            entity_iter: entity_list.into_iter(),
+            entities: &world.entities,
+            tables: &world.storages().tables,
+            archetypes: &world.archetypes,
+            query_state,
+            fetch,
+            filter,
+        }
+    }
+
+    /// Fetches the query result of the next entity in the list that matches the query.
+    ///
+    /// # Safety
+    /// If `QF` is not read only, the entity list must not contain the same entity more than once.
+    #[inline]
+    unsafe fn fetch_next(&mut self) -> Option<QF::Item> {
+        for entity in self.entity_iter.by_ref() {
+            let location = match self.entities.get(entity) {
+                Some(location) => location,
+                None => continue,
+            };
+
+            if !self
+                .query_state
+                .matched_archetypes
+                .contains(location.archetype_id.index())
+            {
+                continue;
+            }
+
+            let archetype = &self.archetypes[location.archetype_id];
+
+            // `archetype` matches the query and `location.index` is in its range
+            self.fetch
+                .set_archetype(&self.query_state.fetch_state, archetype, self.tables);
+            self.filter
+                .set_archetype(&self.query_state.filter_state, archetype, self.tables);
+            if self.filter.archetype_filter_fetch(location.index) {
+                return Some(self.fetch.archetype_fetch(location.index));
+            }
+        }
+        None
+    }
+}
+
+// Iterator type is intentionally implemented only for read-only access, because the entity list
+// may contain the same entity more than once.
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery, I: Iterator<Item = Entity>> Iterator
+    for QueryManyIter<'w, 's, Q, QF, F, I>
+where
+    QF: Fetch<'w, 's, State = Q::State> + ReadOnlyFetch,
+    F::Fetch: FilterFetch,
+{
+    type Item = QF::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFE: the fetch is read only, so the returned items may alias
+        unsafe { self.fetch_next() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, max_size) = self.entity_iter.size_hint();
+        (0, max_size)
+    }
+}
+
+/// An [`Iterator`] over query results in a sorted order.
+///
+/// This struct is created by the [`Query::iter_sorted_by_key`](crate::system::Query::iter_sorted_by_key),
+/// [`Query::iter_sorted_by`](crate::system::Query::iter_sorted_by) and
+/// [`Query::iter_by_entity`](crate::system::Query::iter_by_entity) methods and their `_mut`
+/// variants.
+pub struct QuerySortedIter<'w, 's, Q: WorldQuery, QF, F: WorldQuery>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    iter: QueryManyIter<'w, 's, Q, QF, F, std::vec::IntoIter<Entity>>,
+}
+
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery> QuerySortedIter<'w, 's, Q, QF, F>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    /// # Safety
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `query_state.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    /// `entities` must be distinct entities that match the query.
+    pub(crate) unsafe fn new(
+        world: &'w World,
+        query_state: &'s QueryState<Q, F>,
+        entities: Vec<Entity>,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        QuerySortedIter {
+            iter: QueryManyIter::new(world, query_state, entities, last_change_tick, change_tick),
+        }
+    }
+}
+
+// The sorted entities are distinct, so results of mutable queries never alias.
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery> Iterator for QuerySortedIter<'w, 's, Q, QF, F>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+    type Item = QF::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFE: the entity list does not contain duplicates
+        unsafe { self.iter.fetch_next() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every entity matched the query when it was sorted, and the world cannot change while
+        // this iterator exists
+        self.iter.entity_iter.size_hint()
+    }
+}
+
+impl<'w, 's, Q: WorldQuery, QF, F: WorldQuery> ExactSizeIterator
+    for QuerySortedIter<'w, 's, Q, QF, F>
+where
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+{
+}
+
+/// An [`Iterator`] over pairs of query results, where the second result belongs to the entity
+/// referenced by the `R` component of the first.
+///
+/// This struct is created by the [`Query::join_on`](crate::system::Query::join_on) and
+/// [`Query::join_on_mut`](crate::system::Query::join_on_mut) methods.
+pub struct QueryJoinIter<
+    'w,
+    's,
+    R,
+    Q: WorldQuery,
+    QF,
+    F: WorldQuery,
+    JQ: WorldQuery,
+    JQF,
+    JF: WorldQuery,
+> where
+    R: EntityReference,
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+    JQF: Fetch<'w, 's, State = JQ::State>,
+    JF::Fetch: FilterFetch,
+{
+    world: &'w World,
+    tables: &'w Tables,
+    archetypes: &'w Archetypes,
+    query_state: &'s QueryState<Q, F>,
+    join_state: &'s QueryState<JQ, JF>,
+    table_id_iter: std::slice::Iter<'s, TableId>,
+    archetype_id_iter: std::slice::Iter<'s, ArchetypeId>,
+    fetch: QF,
+    filter: F::Fetch,
+    // `Option<&R>`, as a query that reads `R` does not need to require it.
+    reference_state: <Option<&'static R> as WorldQuery>::State,
+    reference: <Option<&'static R> as WorldQuery>::ReadOnlyFetch,
+    current_len: usize,
+    current_index: usize,
+    join_last_change_tick: Tick,
+    change_tick: Tick,
+    marker: PhantomData<JQF>,
+}
+
+impl<'w, 's, R, Q: WorldQuery, QF, F: WorldQuery, JQ: WorldQuery, JQF, JF: WorldQuery>
+    QueryJoinIter<'w, 's, R, Q, QF, F, JQ, JQF, JF>
+where
+    R: EntityReference,
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+    JQF: Fetch<'w, 's, State = JQ::State>,
+    JF::Fetch: FilterFetch,
+{
+    /// # Safety
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query, and that `query_state` reads `R`.
+    /// If several entities reference the same entity, `JQF` must be read only.
+    /// This does not validate that `world.id()` matches the world ids of the states. Calling
+    /// this on a `world` with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn new(
+        world: &'w World,
+        query_state: &'s QueryState<Q, F>,
+        join_state: &'s QueryState<JQ, JF>,
+        last_change_tick: Tick,
+        join_last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Self {
+        let fetch = QF::init(
+            world,
+            &query_state.fetch_state,
+            last_change_tick,
+            change_tick,
+        );
+        let filter = <F::Fetch as Fetch>::init(
+            world,
+            &query_state.filter_state,
+            last_change_tick,
+            change_tick,
+        );
+        // `R` is initialized, as `query_state` reads it
+        let reference_state = FetchState::try_init(world).unwrap();
+        let reference = Fetch::init(world, &reference_state, last_change_tick, change_tick);
+
+        QueryJoinIter {
+            world,
+            tables: &world.storages().tables,
+            archetypes: &world.archetypes,
+            query_state,
+            join_state,
+            table_id_iter: query_state.matched_table_ids.iter(),
+            archetype_id_iter: query_state.matched_archetype_ids.iter(),
+            fetch,
+            filter,
+            reference_state,
+            reference,
+            current_len: 0,
+            current_index: 0,
+            join_last_change_tick,
+            change_tick,
+            marker: PhantomData,
+        }
+    }
+
+    /// Looks up the entity referenced by `reference` in the joined query.
+    #[inline]
+    unsafe fn join(&self, reference: Option<&R>) -> Option<JQF::Item> {
+        self.join_state
+            .get_unchecked_manual::<JQF>(
+                self.world,
+                reference?.entity(),
+                self.join_last_change_tick,
+                self.change_tick,
+            )
+            .ok()
+    }
+}
+
+impl<'w, 's, R, Q: WorldQuery, QF, F: WorldQuery, JQ: WorldQuery, JQF, JF: WorldQuery> Iterator
+    for QueryJoinIter<'w, 's, R, Q, QF, F, JQ, JQF, JF>
+where
+    R: EntityReference,
+    QF: Fetch<'w, 's, State = Q::State>,
+    F::Fetch: FilterFetch,
+    JQF: Fetch<'w, 's, State = JQ::State>,
+    JF::Fetch: FilterFetch,
+{
+    type Item = (QF::Item, JQF::Item);
+
+    // NOTE: this follows `QueryIter::next`, with the referenced entity fetched alongside each
+    // result of the query.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFE: the caller ensures the access of both states is valid, and every row is visited
+        // once
+        unsafe {
+            if QF::IS_DENSE
+                && F::Fetch::IS_DENSE
+                && <<Option<&R> as WorldQuery>::ReadOnlyFetch as Fetch>::IS_DENSE
+                && self.query_state.is_dense
+            {
+                loop {
+                    if self.current_index == self.current_len {
+                        let table_id = self.table_id_iter.next()?;
+                        let table = &self.tables[*table_id];
+                        self.fetch.set_table(&self.query_state.fetch_state, table);
+                        self.filter.set_table(&self.query_state.filter_state, table);
+                        self.reference.set_table(&self.reference_state, table);
+                        self.current_len = table.len();
+                        self.current_index = 0;
+                        continue;
+                    }
+
+                    let index = self.current_index;
+                    self.current_index += 1;
+                    if !self.filter.table_filter_fetch(index) {
+                        continue;
+                    }
+                    let reference = self.reference.table_fetch(index);
+                    if let Some(joined) = self.join(reference) {
+                        return Some((self.fetch.table_fetch(index), joined));
+                    }
+                }
+            } else {
+                loop {
+                    if self.current_index == self.current_len {
+                        let archetype_id = self.archetype_id_iter.next()?;
+                        let archetype = &self.archetypes[*archetype_id];
+                        self.fetch.set_archetype(
+                            &self.query_state.fetch_state,
+                            archetype,
+                            self.tables,
+                        );
+                        self.filter.set_archetype(
+                            &self.query_state.filter_state,
+                            archetype,
+                            self.tables,
+                        );
+                        self.reference
+                            .set_archetype(&self.reference_state, archetype, self.tables);
+                        self.current_len = archetype.len();
+                        self.current_index = 0;
+                        continue;
+                    }
+
+                    let index = self.current_index;
+                    self.current_index += 1;
+                    if !self.filter.archetype_filter_fetch(index) {
+                        continue;
+                    }
+                    let reference = self.reference.archetype_fetch(index);
+                    if let Some(joined) = self.join(reference) {
+                        return Some((self.fetch.archetype_fetch(index), joined));
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub struct QueryCombinationIter<'w, 's, Q: WorldQuery, QF, F: WorldQuery, const K: usize>
 where
     QF: Fetch<'w, 's, State = Q::State>,
@@ -179,8 +709,8 @@ where
     pub(crate) unsafe fn new(
         world: &'w World,
         query_state: &'s QueryState<Q, F>,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         // Initialize array with cursors.
         // There is no FromIterator on arrays, so instead initialize it manually with MaybeUninit
@@ -359,6 +889,7 @@ struct QueryIterationCursor<
     filter: F::Fetch,
     current_len: usize,
     current_index: usize,
+    is_dense: bool,
     phantom: PhantomData<&'w Q>,
 }
 
@@ -375,6 +906,7 @@ where
             filter: self.filter.clone(),
             current_len: self.current_len,
             current_index: self.current_index,
+            is_dense: self.is_dense,
             phantom: PhantomData,
         }
     }
@@ -388,8 +920,8 @@ where
     unsafe fn init_empty(
         world: &World,
         query_state: &'s QueryState<Q, F>,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         QueryIterationCursor {
             table_id_iter: [].iter(),
@@ -401,8 +933,8 @@ where
     unsafe fn init(
         world: &World,
         query_state: &'s QueryState<Q, F>,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         let fetch = QF::init(
             world,
@@ -423,6 +955,7 @@ where
             archetype_id_iter: query_state.matched_archetype_ids.iter(),
             current_len: 0,
             current_index: 0,
+            is_dense: query_state.is_dense,
             phantom: PhantomData,
         }
     }
@@ -431,7 +964,7 @@ where
     #[inline]
     unsafe fn peek_last(&mut self) -> Option<QF::Item> {
         if self.current_index > 0 {
-            if QF::IS_DENSE && F::Fetch::IS_DENSE {
+            if QF::IS_DENSE && F::Fetch::IS_DENSE && self.is_dense {
                 Some(self.fetch.table_fetch(self.current_index - 1))
             } else {
                 Some(self.fetch.archetype_fetch(self.current_index - 1))
@@ -451,7 +984,7 @@ where
         archetypes: &'w Archetypes,
         query_state: &'s QueryState<Q, F>,
     ) -> Option<QF::Item> {
-        if QF::IS_DENSE && F::Fetch::IS_DENSE {
+        if QF::IS_DENSE && F::Fetch::IS_DENSE && self.is_dense {
             loop {
                 if self.current_index == self.current_len {
                     let table_id = self.table_id_iter.next()?;
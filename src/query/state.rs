@@ -1,12 +1,13 @@
 use crate::{
-    archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
-    component::ComponentId,
+    archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId, Archetypes},
+    component::{ComponentId, Tick},
     entity::Entity,
     query::{
-        Access, Fetch, FetchState, FilterFetch, FilteredAccess, NopFetch, QueryCombinationIter,
-        QueryIter, WorldQuery,
+        Access, ChunkFetch, Fetch, FetchState, FilterFetch, FilteredAccess, NopFetch,
+        QueryChunkIter, QueryCombinationIter, QueryIter, QueryManyIter, WorldQuery,
     },
     storage::TableId,
+    task_pool::Job,
     world::{World, WorldId},
 };
 use fixedbitset::FixedBitSet;
@@ -29,6 +30,9 @@ where
     pub(crate) matched_archetype_ids: Vec<ArchetypeId>,
     pub(crate) fetch_state: Q::State,
     pub(crate) filter_state: F::State,
+    // NOTE: false if only some archetypes of the matched tables are matched, which forces
+    // archetype iteration even when the fetch and filter are dense (see `try_transmute`)
+    pub(crate) is_dense: bool,
 }
 
 impl<Q: WorldQuery, F: WorldQuery> QueryState<Q, F>
@@ -39,7 +43,12 @@ where
     pub fn new(world: &mut World) -> Self {
         let fetch_state = <Q::State as FetchState>::init(world);
         let filter_state = <F::State as FetchState>::init(world);
+        let mut state = Self::from_states(world, fetch_state, filter_state);
+        state.update_archetypes(world);
+        state
+    }
 
+    fn from_states(world: &World, fetch_state: Q::State, filter_state: F::State) -> Self {
         let mut component_access = FilteredAccess::default();
         fetch_state.update_component_access(&mut component_access);
 
@@ -53,7 +62,7 @@ where
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
-        let mut state = Self {
+        Self {
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_table_ids: Vec::new(),
@@ -64,14 +73,61 @@ where
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             archetype_component_access: Default::default(),
+            is_dense: true,
+        }
+    }
+
+    /// Creates a [`QueryState`] for `NewQ` with the same filter `F` as this state, that only
+    /// matches the archetypes matched by this state.
+    ///
+    /// The new state is not meant to be updated with new archetypes, as it could then match
+    /// archetypes this state does not.
+    ///
+    /// Returns [`QueryTransmuteError::IncompatibleAccess`] if `NewQ` accesses components in a way
+    /// this state does not, and [`QueryTransmuteError::UnsupportedFilter`] if the filter state
+    /// can't be recreated through [`FetchState::try_init`].
+    pub(crate) fn try_transmute<NewQ: WorldQuery>(
+        &self,
+        world: &World,
+    ) -> Result<QueryState<NewQ, F>, QueryTransmuteError> {
+        self.validate_world(world);
+        let incompatible = || QueryTransmuteError::IncompatibleAccess {
+            from: std::any::type_name::<Q>(),
+            to: std::any::type_name::<NewQ>(),
         };
-        state.update_archetypes(world);
+        // Components that are not initialized cannot be accessed by this state either.
+        let fetch_state = <NewQ::State as FetchState>::try_init(world).ok_or_else(incompatible)?;
+        // The filter's components were initialized by this state, so this only fails if the
+        // filter does not support `try_init`.
+        let filter_state = <F::State as FetchState>::try_init(world).ok_or(
+            QueryTransmuteError::UnsupportedFilter {
+                filter: std::any::type_name::<F>(),
+            },
+        )?;
+        let mut state = QueryState::<NewQ, F>::from_states(world, fetch_state, filter_state);
+        // The new state only matches the archetypes of this state, so it is restricted by all of
+        // its `With` and `Without` filters, including those implied by components `NewQ` drops.
         state
+            .component_access
+            .extend_filters(&self.component_access);
+        if !state.component_access.is_subset(&self.component_access) {
+            return Err(incompatible());
+        }
+
+        state.archetype_generation = self.archetype_generation;
+        // A table is only iterated as a whole if this state did so as well, otherwise the new
+        // state could reach entities of archetypes this state does not match.
+        state.is_dense = self.is_dense && Q::Fetch::IS_DENSE && F::Fetch::IS_DENSE;
+        let archetypes = world.archetypes();
+        for archetype_id in self.matched_archetype_ids.iter() {
+            state.new_archetype(&archetypes[*archetype_id]);
+        }
+        Ok(state)
     }
 
     /// Checks if the query is empty for the given [`World`], where the last change and current tick are given.
     #[inline]
-    pub fn is_empty(&self, world: &World, last_change_tick: u32, change_tick: u32) -> bool {
+    pub fn is_empty(&self, world: &World, last_change_tick: Tick, change_tick: Tick) -> bool {
         // SAFE: NopFetch does not access any members while &self ensures no one has exclusive access
         unsafe {
             self.iter_unchecked_manual::<NopFetch<Q::State>>(world, last_change_tick, change_tick)
@@ -89,6 +145,7 @@ where
     pub fn update_archetypes(&mut self, world: &World) {
         self.validate_world(world);
         let archetypes = world.archetypes();
+        self.remove_pruned_archetypes(archetypes);
         let new_generation = archetypes.generation();
         let old_generation = std::mem::replace(&mut self.archetype_generation, new_generation);
         let archetype_index_range = old_generation.value()..new_generation.value();
@@ -98,6 +155,39 @@ where
         }
     }
 
+    /// Drops the archetypes and tables removed by [`World::prune_empty_storage`] from the
+    /// matched lists, if any were removed since the last call. The matched tables are rebuilt
+    /// from the remaining matched archetypes.
+    ///
+    /// This must be called before iterating with a state whose archetypes are updated through
+    /// [`QueryState::new_archetype`] rather than [`QueryState::update_archetypes`].
+    pub(crate) fn remove_pruned_archetypes(&mut self, archetypes: &Archetypes) {
+        let removals = archetypes.generation().removals();
+        if self.archetype_generation.removals() == removals {
+            return;
+        }
+        self.archetype_generation = self.archetype_generation.with_removals(removals);
+
+        let matched_archetypes = &mut self.matched_archetypes;
+        self.matched_archetype_ids.retain(|archetype_id| {
+            let is_removed = archetypes[*archetype_id].is_removed();
+            if is_removed {
+                matched_archetypes.set(archetype_id.index(), false);
+            }
+            !is_removed
+        });
+        self.matched_tables.clear();
+        self.matched_table_ids.clear();
+        for archetype_id in self.matched_archetype_ids.iter() {
+            let table_id = archetypes[*archetype_id].table_id();
+            if !self.matched_tables.contains(table_id.index()) {
+                self.matched_tables.grow(table_id.index() + 1);
+                self.matched_tables.set(table_id.index(), true);
+                self.matched_table_ids.push(table_id);
+            }
+        }
+    }
+
     #[inline]
     pub fn validate_world(&self, world: &World) {
         if world.id() != self.world_id {
@@ -108,7 +198,8 @@ where
 
     /// Creates a new [`Archetype`].
     pub fn new_archetype(&mut self, archetype: &Archetype) {
-        if self.fetch_state.matches_archetype(archetype)
+        if !archetype.is_removed()
+            && self.fetch_state.matches_archetype(archetype)
             && self.filter_state.matches_archetype(archetype)
         {
             self.fetch_state
@@ -188,6 +279,53 @@ where
         }
     }
 
+    /// Gets the query results for the given [`World`] and array of [`Entity`]s.
+    ///
+    /// Returns an error if any of the entities does not exist or does not match the query.
+    ///
+    /// This can only be called for read-only queries, see [`Self::get_many_mut`] for
+    /// write-queries.
+    #[inline]
+    pub fn get_many<'w, 's, const N: usize>(
+        &'s mut self,
+        world: &'w World,
+        entities: [Entity; N],
+    ) -> Result<[<Q::ReadOnlyFetch as Fetch<'w, 's>>::Item; N], QueryEntityError> {
+        self.update_archetypes(world);
+        // SAFETY: query is read only
+        unsafe {
+            self.get_many_unchecked_manual::<Q::ReadOnlyFetch, N>(
+                world,
+                entities,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+        }
+    }
+
+    /// Gets the query results for the given [`World`] and array of distinct [`Entity`]s.
+    ///
+    /// Returns [`QueryEntityError::AliasedMutability`] if an entity appears more than once,
+    /// or another error if any of the entities does not exist or does not match the query.
+    #[inline]
+    pub fn get_many_mut<'w, 's, const N: usize>(
+        &'s mut self,
+        world: &'w mut World,
+        entities: [Entity; N],
+    ) -> Result<[<Q::Fetch as Fetch<'w, 's>>::Item; N], QueryEntityError> {
+        self.update_archetypes(world);
+        verify_entities_unique(&entities)?;
+        // SAFETY: query has unique world access and the entities are distinct
+        unsafe {
+            self.get_many_unchecked_manual::<Q::Fetch, N>(
+                world,
+                entities,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+        }
+    }
+
     /// Gets the query result for the given [`World`] and [`Entity`].
     ///
     /// # Safety
@@ -220,8 +358,8 @@ where
         &'s self,
         world: &'w World,
         entity: Entity,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Result<QF::Item, QueryEntityError> {
         let location = world
             .entities
@@ -247,6 +385,42 @@ where
         }
     }
 
+    /// Gets the query results for the given [`World`] and array of [`Entity`]s, where the last
+    /// change and the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query, and that `entities` are distinct if
+    /// `QF` is not read only.
+    pub(crate) unsafe fn get_many_unchecked_manual<
+        'w,
+        's,
+        QF: Fetch<'w, 's, State = Q::State>,
+        const N: usize,
+    >(
+        &'s self,
+        world: &'w World,
+        entities: [Entity; N],
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Result<[QF::Item; N], QueryEntityError> {
+        // Check every entity before fetching any item, so no item is created on failure.
+        for entity in entities {
+            self.get_unchecked_manual::<NopFetch<Q::State>>(
+                world,
+                entity,
+                last_change_tick,
+                change_tick,
+            )?;
+        }
+
+        Ok(entities.map(|entity| {
+            self.get_unchecked_manual::<QF>(world, entity, last_change_tick, change_tick)
+                .unwrap()
+        }))
+    }
+
     /// Returns an [`Iterator`] over the query results for the given [`World`].
     ///
     /// This can only be called for read-only queries, see [`Self::iter_mut`] for write-queries.
@@ -382,6 +556,242 @@ where
         )
     }
 
+    /// Returns an [`Iterator`] over the read-only query results of the given list of
+    /// [`Entity`]s. Entities that don't exist or don't match the query are skipped.
+    #[inline]
+    pub fn iter_many<'w, 's, EntityList: IntoIterator<Item = Entity>>(
+        &'s mut self,
+        world: &'w World,
+        entities: EntityList,
+    ) -> QueryManyIter<'w, 's, Q, Q::ReadOnlyFetch, F, EntityList::IntoIter> {
+        self.update_archetypes(world);
+        // SAFETY: query is read only
+        unsafe {
+            self.iter_many_unchecked_manual(
+                world,
+                entities,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results of the given list of [`Entity`]s,
+    /// where the last change and the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    #[inline]
+    pub(crate) unsafe fn iter_many_unchecked_manual<
+        'w,
+        's,
+        QF: Fetch<'w, 's, State = Q::State>,
+        EntityList: IntoIterator<Item = Entity>,
+    >(
+        &'s self,
+        world: &'w World,
+        entities: EntityList,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> QueryManyIter<'w, 's, Q, QF, F, EntityList::IntoIter> {
+        QueryManyIter::new(world, self, entities, last_change_tick, change_tick)
+    }
+
+    /// Runs `func` on each chunk of read-only query results for the given [`World`].
+    ///
+    /// A chunk holds the results of a run of consecutive rows of a table, e.g. `&[T]` for a
+    /// `&T` query, so that it can be processed in bulk. Sparse set components are gathered into a
+    /// `Vec` per chunk instead, and tags are fetched as slices of zero-sized values, see
+    /// [`ChunkStorage`](crate::query::ChunkStorage). If the query accesses sparse set or tag
+    /// components, the rows of each archetype that pass the filter are split into separate
+    /// chunks.
+    ///
+    /// This can only be called for read-only queries, see [`Self::for_each_chunk_mut`] for
+    /// write-queries.
+    #[inline]
+    pub fn for_each_chunk<'w, 's>(
+        &'s mut self,
+        world: &'w World,
+        func: impl FnMut(<Q::ReadOnlyFetch as ChunkFetch<'w, 's>>::Chunk),
+    ) where
+        Q::ReadOnlyFetch: ChunkFetch<'w, 's>,
+    {
+        self.update_archetypes(world);
+        // SAFETY: query is read only
+        unsafe {
+            self.iter_chunks_unchecked_manual::<Q::ReadOnlyFetch>(
+                world,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+            .for_each(func);
+        }
+    }
+
+    /// Runs `func` on each chunk of query results for the given [`World`].
+    ///
+    /// Mutable components are returned as [`ChunkMut`](crate::query::ChunkMut)s. See
+    /// [`Self::for_each_chunk`] for how results are grouped into chunks.
+    #[inline]
+    pub fn for_each_chunk_mut<'w, 's>(
+        &'s mut self,
+        world: &'w mut World,
+        func: impl FnMut(<Q::Fetch as ChunkFetch<'w, 's>>::Chunk),
+    ) where
+        Q::Fetch: ChunkFetch<'w, 's>,
+    {
+        self.update_archetypes(world);
+        // SAFETY: query has unique world access
+        unsafe {
+            self.iter_chunks_unchecked_manual::<Q::Fetch>(
+                world,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+            .for_each(func);
+        }
+    }
+
+    /// Returns an [`Iterator`] over chunks of query results for the given [`World`], where the
+    /// last change and the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    #[inline]
+    pub(crate) unsafe fn iter_chunks_unchecked_manual<
+        'w,
+        's,
+        QF: ChunkFetch<'w, 's, State = Q::State>,
+    >(
+        &'s self,
+        world: &'w World,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> QueryChunkIter<'w, 's, Q, QF, F> {
+        QueryChunkIter::new(world, self, last_change_tick, change_tick)
+    }
+
+    /// Returns the entities that match the query for the given [`World`], in iteration order,
+    /// where the last change and the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn matched_entities_unchecked_manual(
+        &self,
+        world: &World,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) -> Vec<Entity> {
+        let mut filter =
+            <F::Fetch as Fetch>::init(world, &self.filter_state, last_change_tick, change_tick);
+        let mut entities = Vec::new();
+        if Q::Fetch::IS_DENSE && F::Fetch::IS_DENSE && self.is_dense {
+            let tables = &world.storages().tables;
+            for table_id in self.matched_table_ids.iter() {
+                let table = &tables[*table_id];
+                filter.set_table(&self.filter_state, table);
+                for (table_index, entity) in table.entities().iter().enumerate() {
+                    if filter.table_filter_fetch(table_index) {
+                        entities.push(*entity);
+                    }
+                }
+            }
+        } else {
+            let archetypes = &world.archetypes;
+            let tables = &world.storages().tables;
+            for archetype_id in self.matched_archetype_ids.iter() {
+                let archetype = &archetypes[*archetype_id];
+                filter.set_archetype(&self.filter_state, archetype, tables);
+                for (archetype_index, entity) in archetype.entities().iter().enumerate() {
+                    if filter.archetype_filter_fetch(archetype_index) {
+                        entities.push(*entity);
+                    }
+                }
+            }
+        }
+        entities
+    }
+
+    /// Returns the entities that match the query for the given [`World`], ordered by `compare`
+    /// applied to their read-only query results, where the last change and the current change
+    /// tick are given. Entities that compare equal are ordered by [`Entity::id`].
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure the query can
+    /// read the components it queries.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn sorted_entities_unchecked_manual(
+        &self,
+        world: &World,
+        last_change_tick: Tick,
+        change_tick: Tick,
+        mut compare: impl FnMut(
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+        ) -> std::cmp::Ordering,
+    ) -> Vec<Entity> {
+        let entities = self.matched_entities_unchecked_manual(world, last_change_tick, change_tick);
+        let mut results = self
+            .iter_many_unchecked_manual::<Q::ReadOnlyFetch, _>(
+                world,
+                entities.iter().copied(),
+                last_change_tick,
+                change_tick,
+            )
+            .zip(entities.iter().copied())
+            .collect::<Vec<_>>();
+        results.sort_unstable_by(|(a, a_entity), (b, b_entity)| {
+            compare(a, b).then_with(|| a_entity.id().cmp(&b_entity.id()))
+        });
+        results.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    /// Returns the entities that match the query for the given [`World`], ordered by the keys
+    /// `f` returns for their read-only query results, where the last change and the current
+    /// change tick are given. Entities with equal keys are ordered by [`Entity::id`].
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure the query can
+    /// read the components it queries.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn sorted_entities_by_key_unchecked_manual<K: Ord>(
+        &self,
+        world: &World,
+        last_change_tick: Tick,
+        change_tick: Tick,
+        mut f: impl FnMut(&<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item) -> K,
+    ) -> Vec<Entity> {
+        let entities = self.matched_entities_unchecked_manual(world, last_change_tick, change_tick);
+        let mut keys = self
+            .iter_many_unchecked_manual::<Q::ReadOnlyFetch, _>(
+                world,
+                entities.iter().copied(),
+                last_change_tick,
+                change_tick,
+            )
+            .zip(entities.iter().copied())
+            .map(|(item, entity)| (f(&item), entity))
+            .collect::<Vec<_>>();
+        keys.sort_unstable_by(|(a, a_entity), (b, b_entity)| {
+            a.cmp(b).then_with(|| a_entity.id().cmp(&b_entity.id()))
+        });
+        keys.into_iter().map(|(_, entity)| entity).collect()
+    }
+
     /// Returns an [`Iterator`] for the given [`World`], where the last change and
     /// the current change tick are given.
     ///
@@ -395,8 +805,8 @@ where
     pub(crate) unsafe fn iter_unchecked_manual<'w, 's, QF: Fetch<'w, 's, State = Q::State>>(
         &'s self,
         world: &'w World,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> QueryIter<'w, 's, Q, QF, F> {
         QueryIter::new(world, self, last_change_tick, change_tick)
     }
@@ -420,8 +830,8 @@ where
     >(
         &'s self,
         world: &'w World,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> QueryCombinationIter<'w, 's, Q, QF, F, K> {
         QueryCombinationIter::new(world, self, last_change_tick, change_tick)
     }
@@ -492,6 +902,57 @@ where
         );
     }
 
+    /// Runs `func` on each read-only query result in parallel for the given [`World`], using the
+    /// world's [`TaskPool`](crate::task_pool::TaskPool).
+    ///
+    /// Tables (or archetypes, if the query accesses sparse set or tag components) are split into
+    /// batches of up to `batch_size` entities, and each batch is run as a separate job.
+    ///
+    /// This can only be called for read-only queries, see [`Self::par_for_each_mut`] for
+    /// write-queries.
+    #[inline]
+    pub fn par_for_each<'w, 's, FN: Fn(<Q::ReadOnlyFetch as Fetch<'w, 's>>::Item) + Send + Sync>(
+        &'s mut self,
+        world: &'w World,
+        batch_size: usize,
+        func: FN,
+    ) {
+        // SAFETY: query is read only
+        unsafe {
+            self.update_archetypes(world);
+            self.par_for_each_unchecked_manual::<Q::ReadOnlyFetch, FN>(
+                world,
+                batch_size,
+                func,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            );
+        }
+    }
+
+    /// Runs `func` on each query result in parallel for the given [`World`], using the world's
+    /// [`TaskPool`](crate::task_pool::TaskPool). See [`Self::par_for_each`] for how results are
+    /// batched.
+    #[inline]
+    pub fn par_for_each_mut<'w, 's, FN: Fn(<Q::Fetch as Fetch<'w, 's>>::Item) + Send + Sync>(
+        &'s mut self,
+        world: &'w mut World,
+        batch_size: usize,
+        func: FN,
+    ) {
+        // SAFETY: query has unique world access
+        unsafe {
+            self.update_archetypes(world);
+            self.par_for_each_unchecked_manual::<Q::Fetch, FN>(
+                world,
+                batch_size,
+                func,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            );
+        }
+    }
+
     /// Runs `func` on each query result for the given [`World`], where the last change and
     /// the current change tick are given. This is faster than the equivalent
     /// iter() method, but cannot be chained like a normal [`Iterator`].
@@ -511,15 +972,15 @@ where
         &'s self,
         world: &'w World,
         mut func: FN,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) {
         // NOTE: If you are changing query iteration code, remember to update the following places, where relevant:
         // QueryIter, QueryIterationCursor, QueryState::for_each_unchecked_manual, QueryState::par_for_each_unchecked_manual
         let mut fetch = QF::init(world, &self.fetch_state, last_change_tick, change_tick);
         let mut filter =
             <F::Fetch as Fetch>::init(world, &self.filter_state, last_change_tick, change_tick);
-        if Q::Fetch::IS_DENSE && F::Fetch::IS_DENSE {
+        if Q::Fetch::IS_DENSE && F::Fetch::IS_DENSE && self.is_dense {
             let tables = &world.storages().tables;
             for table_id in self.matched_table_ids.iter() {
                 let table = &tables[*table_id];
@@ -551,6 +1012,103 @@ where
             }
         }
     }
+
+    /// Runs `func` on each query result in parallel for the given [`World`], where the last
+    /// change and the current change tick are given.
+    ///
+    /// # Panics
+    ///
+    /// If `batch_size` is zero.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    pub(crate) unsafe fn par_for_each_unchecked_manual<
+        'w,
+        's,
+        QF: Fetch<'w, 's, State = Q::State>,
+        FN: Fn(QF::Item) + Send + Sync,
+    >(
+        &'s self,
+        world: &'w World,
+        batch_size: usize,
+        func: FN,
+        last_change_tick: Tick,
+        change_tick: Tick,
+    ) {
+        // NOTE: If you are changing query iteration code, remember to update the following places, where relevant:
+        // QueryIter, QueryIterationCursor, QueryState::for_each_unchecked_manual, QueryState::par_for_each_unchecked_manual
+        assert!(batch_size > 0, "batch_size must be greater than zero");
+        let func = &func;
+        let mut jobs: Vec<Job<'_>> = Vec::new();
+        // Every job covers a distinct range of entities, so jobs never alias each other's
+        // mutable components. The fetches are created inside the jobs, as they are not `Send`.
+        if QF::IS_DENSE && F::Fetch::IS_DENSE && self.is_dense {
+            let tables = &world.storages().tables;
+            for table_id in self.matched_table_ids.iter() {
+                let table_len = tables[*table_id].len();
+                let mut offset = 0;
+                while offset < table_len {
+                    let batch = offset..table_len.min(offset + batch_size);
+                    jobs.push(Box::new(move || {
+                        let table = &world.storages().tables[*table_id];
+                        let mut fetch =
+                            QF::init(world, &self.fetch_state, last_change_tick, change_tick);
+                        let mut filter = <F::Fetch as Fetch>::init(
+                            world,
+                            &self.filter_state,
+                            last_change_tick,
+                            change_tick,
+                        );
+                        fetch.set_table(&self.fetch_state, table);
+                        filter.set_table(&self.filter_state, table);
+
+                        for table_index in batch {
+                            if !filter.table_filter_fetch(table_index) {
+                                continue;
+                            }
+                            func(fetch.table_fetch(table_index));
+                        }
+                    }));
+                    offset += batch_size;
+                }
+            }
+        } else {
+            for archetype_id in self.matched_archetype_ids.iter() {
+                let archetype_len = world.archetypes[*archetype_id].len();
+                let mut offset = 0;
+                while offset < archetype_len {
+                    let batch = offset..archetype_len.min(offset + batch_size);
+                    jobs.push(Box::new(move || {
+                        let archetype = &world.archetypes[*archetype_id];
+                        let tables = &world.storages().tables;
+                        let mut fetch =
+                            QF::init(world, &self.fetch_state, last_change_tick, change_tick);
+                        let mut filter = <F::Fetch as Fetch>::init(
+                            world,
+                            &self.filter_state,
+                            last_change_tick,
+                            change_tick,
+                        );
+                        fetch.set_archetype(&self.fetch_state, archetype, tables);
+                        filter.set_archetype(&self.filter_state, archetype, tables);
+
+                        for archetype_index in batch {
+                            if !filter.archetype_filter_fetch(archetype_index) {
+                                continue;
+                            }
+                            func(fetch.archetype_fetch(archetype_index));
+                        }
+                    }));
+                    offset += batch_size;
+                }
+            }
+        }
+        world.task_pool().scope(jobs);
+    }
 }
 
 /// An error that occurs when retrieving a specific [`Entity`]'s query result.
@@ -560,4 +1118,32 @@ pub enum QueryEntityError {
     QueryDoesNotMatch,
     #[error("The requested entity does not exist.")]
     NoSuchEntity,
+    #[error("The entity {0:?} was requested mutably more than once.")]
+    AliasedMutability(Entity),
+}
+
+/// An error that occurs when transmuting a query into another [`WorldQuery`].
+#[derive(Error, Debug)]
+pub enum QueryTransmuteError {
+    #[error(
+        "Cannot transmute {from} into {to}, as {to} accesses components that {from} does not."
+    )]
+    IncompatibleAccess {
+        from: &'static str,
+        to: &'static str,
+    },
+    #[error("Cannot transmute a query filtered by {filter}, as the filter does not support `FetchState::try_init`.")]
+    UnsupportedFilter { filter: &'static str },
+}
+
+/// Returns [`QueryEntityError::AliasedMutability`] if `entities` contains the same entity twice.
+pub(crate) fn verify_entities_unique<const N: usize>(
+    entities: &[Entity; N],
+) -> Result<(), QueryEntityError> {
+    for (i, entity) in entities.iter().enumerate() {
+        if entities[..i].contains(entity) {
+            return Err(QueryEntityError::AliasedMutability(*entity));
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,86 @@
+//! Constructors for common run criteria.
+//!
+//! Each constructor returns a [`RunCriteriaDescriptor`], which can be combined with
+//! [`RunCriteriaDescriptor::and`], [`RunCriteriaDescriptor::or`] and `!`.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::schedule::common_criteria::{resource_changed, resource_exists};
+//!
+//! struct Paused;
+//! struct Settings {
+//!     volume: f32,
+//! }
+//!
+//! fn apply_settings_system(settings: Res<Settings>) {
+//!     println!("volume: {}", settings.volume);
+//! }
+//!
+//! let stage = SystemStage::parallel().with_system(
+//!     apply_settings_system
+//!         .with_run_criteria(resource_changed::<Settings>().and(!resource_exists::<Paused>())),
+//! );
+//! ```
+
+use crate::{
+    component::Component,
+    event::EventReader,
+    query::With,
+    schedule::{new_run_criteria_descriptor, RunCriteriaDescriptor, ShouldRun, State, StateData},
+    system::{In, IntoChainSystem, IntoSystem, Query, Res, Resource},
+};
+
+/// Creates a criteria from a system returning `bool`.
+fn bool_criteria<Param>(system: impl IntoSystem<(), bool, Param>) -> RunCriteriaDescriptor {
+    new_run_criteria_descriptor(Box::new(system.chain(should_run_adapter)))
+}
+
+fn should_run_adapter(In(should_run): In<bool>) -> ShouldRun {
+    should_run.into()
+}
+
+/// Runs the system if the resource `T` exists.
+pub fn resource_exists<T: Resource>() -> RunCriteriaDescriptor {
+    bool_criteria(|resource: Option<Res<T>>| resource.is_some())
+}
+
+/// Runs the system if the resource `T` was added since the criteria was last checked.
+pub fn resource_added<T: Resource>() -> RunCriteriaDescriptor {
+    bool_criteria(|resource: Option<Res<T>>| resource.is_some_and(|resource| resource.is_added()))
+}
+
+/// Runs the system if the resource `T` was added or changed since the criteria was last checked.
+pub fn resource_changed<T: Resource>() -> RunCriteriaDescriptor {
+    bool_criteria(|resource: Option<Res<T>>| resource.is_some_and(|resource| resource.is_changed()))
+}
+
+/// Runs the system if the resource `T` exists and is equal to `value`.
+pub fn resource_equals<T: Resource + PartialEq>(value: T) -> RunCriteriaDescriptor {
+    bool_criteria(move |resource: Option<Res<T>>| {
+        resource.is_some_and(|resource| *resource == value)
+    })
+}
+
+/// Runs the system if the current state of the [`State<T>`] resource is `state`.
+///
+/// Unlike [`State::on_update`], this doesn't wait for state transitions to finish, and doesn't
+/// need the state's driver.
+pub fn state_is<T: StateData>(state: T) -> RunCriteriaDescriptor {
+    bool_criteria(move |current: Option<Res<State<T>>>| {
+        current.is_some_and(|current| *current.current() == state)
+    })
+}
+
+/// Runs the system if any entity has the `T` component.
+pub fn any_with_component<T: Component>() -> RunCriteriaDescriptor {
+    bool_criteria(|query: Query<(), With<T>>| !query.is_empty())
+}
+
+/// Runs the system if an `E` event was sent since the criteria was last checked.
+///
+/// The events are still visible to the [`EventReader`]s of the system.
+pub fn on_event<E: Resource>() -> RunCriteriaDescriptor {
+    bool_criteria(|mut events: EventReader<E>| events.iter().next().is_some())
+}
@@ -3,9 +3,11 @@
 //! When using Bevy ECS, systems are usually not run directly, but are inserted into a
 //!  [`Stage`], which then lives within a [`Schedule`].
 
+pub mod common_criteria;
 mod executor;
 pub mod graph_utils;
 mod label;
+mod registry;
 mod run_criteria;
 mod stage;
 mod state;
@@ -16,6 +18,7 @@ mod system_set;
 pub use executor::*;
 pub use graph_utils::GraphNode;
 pub use label::*;
+pub use registry::*;
 pub use run_criteria::*;
 pub use stage::*;
 pub use state::*;
@@ -104,9 +107,16 @@ impl Schedule {
     /// schedule.add_stage("my_stage", SystemStage::parallel());
     /// ```
     pub fn add_stage<S: Stage>(&mut self, label: impl StageLabel, stage: S) -> &mut Self {
-        let label: Box<dyn StageLabel> = Box::new(label);
+        self.add_boxed_stage(Box::new(label), Box::new(stage))
+    }
+
+    pub(crate) fn add_boxed_stage(
+        &mut self,
+        label: BoxedStageLabel,
+        stage: Box<dyn Stage>,
+    ) -> &mut Self {
         self.stage_order.push(label.clone());
-        let prev = self.stages.insert(label.clone(), Box::new(stage));
+        let prev = self.stages.insert(label.clone(), stage);
         if prev.is_some() {
             panic!("Stage already exists: {:?}.", label);
         }
@@ -0,0 +1,418 @@
+use crate::schedule::{
+    new_run_criteria_descriptor, BoxedRunCriteriaLabel, BoxedStageLabel, BoxedSystemLabel,
+    DuplicateLabelStrategy, IntoSystemDescriptor, RunCriteriaDescriptor,
+    RunCriteriaDescriptorOrLabel, RunCriteriaLabel, Schedule, ShouldRun, StageLabel, State,
+    StateData, SystemDescriptor, SystemLabel, SystemSet, SystemStage,
+};
+use crate::system::IntoSystem;
+use bevy_utils::{HashMap, HashSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use thiserror::Error;
+
+type SystemFactory = Box<dyn Fn() -> SystemDescriptor + Send + Sync>;
+type RunCriteriaFactory = Box<dyn Fn() -> RunCriteriaDescriptor + Send + Sync>;
+type StateFactory = Box<dyn Fn(StateCallbackConfig) -> RunCriteriaDescriptor + Send + Sync>;
+
+struct RegisteredState {
+    run_criteria: StateFactory,
+    driver: fn() -> SystemSet,
+}
+
+/// A collection of named factories for systems, run criteria, labels and states.
+///
+/// Registering everything a game may schedule under a stable name makes the schedule
+/// addressable from data: [`SystemRegistry::build_schedule`] turns a [`ScheduleConfig`] (which
+/// can be deserialized with the `serde` feature) into a [`Schedule`], so systems can be reordered
+/// or disabled without recompiling. The registry is usually stored as a resource so that
+/// consoles and tools can list what is available.
+///
+/// Label names that were not registered are used verbatim as string labels.
+#[derive(Default)]
+pub struct SystemRegistry {
+    systems: HashMap<Cow<'static, str>, SystemFactory>,
+    run_criteria: HashMap<Cow<'static, str>, RunCriteriaFactory>,
+    states: HashMap<Cow<'static, str>, RegisteredState>,
+    stage_labels: HashMap<Cow<'static, str>, BoxedStageLabel>,
+    system_labels: HashMap<Cow<'static, str>, BoxedSystemLabel>,
+    run_criteria_labels: HashMap<Cow<'static, str>, BoxedRunCriteriaLabel>,
+}
+
+impl SystemRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers `system` under `name`. A new copy of the system is created every time the name
+    /// is used in a [`ScheduleConfig`].
+    pub fn register_system<Params>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        system: impl IntoSystemDescriptor<Params> + Clone + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_system_factory(name, move || system.clone())
+    }
+
+    /// Registers a function creating the system named `name`. Use this for descriptors that
+    /// cannot be cloned, such as exclusive systems.
+    pub fn register_system_factory<Params, D: IntoSystemDescriptor<Params>>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        factory: impl Fn() -> D + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.systems
+            .insert(name.into(), Box::new(move || factory().into_descriptor()));
+        self
+    }
+
+    /// Registers the run criteria `system` under `name`.
+    pub fn register_run_criteria<Param>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        system: impl IntoSystem<(), ShouldRun, Param> + Clone + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_run_criteria_factory(name, move || {
+            new_run_criteria_descriptor(Box::new(system.clone().system()))
+        })
+    }
+
+    /// Registers a function creating the run criteria named `name`.
+    pub fn register_run_criteria_factory(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        factory: impl Fn() -> RunCriteriaDescriptor + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.run_criteria.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Registers the state value `state` under `name`, making the run criteria of all its
+    /// [`State`] callbacks available to [`RunCriteriaConfig::State`].
+    pub fn register_state<T: StateData>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        state: T,
+    ) -> &mut Self {
+        let run_criteria: StateFactory = Box::new(move |callback| {
+            let state = state.clone();
+            match callback {
+                StateCallbackConfig::Update => State::on_update(state),
+                StateCallbackConfig::InactiveUpdate => State::on_inactive_update(state),
+                StateCallbackConfig::InStackUpdate => State::on_in_stack_update(state),
+                StateCallbackConfig::Enter => State::on_enter(state),
+                StateCallbackConfig::Exit => State::on_exit(state),
+                StateCallbackConfig::Pause => State::on_pause(state),
+                StateCallbackConfig::Resume => State::on_resume(state),
+            }
+        });
+        self.states.insert(
+            name.into(),
+            RegisteredState {
+                run_criteria,
+                driver: State::<T>::get_driver,
+            },
+        );
+        self
+    }
+
+    pub fn register_stage_label(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        label: impl StageLabel,
+    ) -> &mut Self {
+        self.stage_labels.insert(name.into(), Box::new(label));
+        self
+    }
+
+    pub fn register_system_label(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        label: impl SystemLabel,
+    ) -> &mut Self {
+        self.system_labels.insert(name.into(), Box::new(label));
+        self
+    }
+
+    pub fn register_run_criteria_label(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        label: impl RunCriteriaLabel,
+    ) -> &mut Self {
+        self.run_criteria_labels
+            .insert(name.into(), Box::new(label));
+        self
+    }
+
+    /// Returns true if a system is registered under `name`.
+    pub fn contains_system(&self, name: &str) -> bool {
+        self.systems.contains_key(name)
+    }
+
+    /// Iterates over the names of all registered systems, in arbitrary order.
+    pub fn system_names(&self) -> impl Iterator<Item = &str> {
+        self.systems.keys().map(|name| name.as_ref())
+    }
+
+    /// Iterates over the names of all registered run criteria, in arbitrary order.
+    pub fn run_criteria_names(&self) -> impl Iterator<Item = &str> {
+        self.run_criteria.keys().map(|name| name.as_ref())
+    }
+
+    /// Iterates over the names of all registered states, in arbitrary order.
+    pub fn state_names(&self) -> impl Iterator<Item = &str> {
+        self.states.keys().map(|name| name.as_ref())
+    }
+
+    /// Creates a new instance of the system registered under `name`.
+    pub fn create_system(&self, name: &str) -> Result<SystemDescriptor, ScheduleConfigError> {
+        self.systems
+            .get(name)
+            .map(|factory| factory())
+            .ok_or_else(|| ScheduleConfigError::UnknownSystem(name.to_string()))
+    }
+
+    /// Creates a new instance of the run criteria registered under `name`.
+    pub fn create_run_criteria(
+        &self,
+        name: &str,
+    ) -> Result<RunCriteriaDescriptor, ScheduleConfigError> {
+        self.run_criteria
+            .get(name)
+            .map(|factory| factory())
+            .ok_or_else(|| ScheduleConfigError::UnknownRunCriteria(name.to_string()))
+    }
+
+    /// Builds a [`Schedule`] containing the stages of `config`, in order.
+    pub fn build_schedule(&self, config: &ScheduleConfig) -> Result<Schedule, ScheduleConfigError> {
+        let mut schedule = Schedule::default();
+        // compare resolved labels, as different names can be registered for the same label
+        let mut stage_labels = HashSet::default();
+        for stage_config in config.stages.iter() {
+            let label = self.stage_label(&stage_config.label);
+            if !stage_labels.insert(label.clone()) {
+                return Err(ScheduleConfigError::DuplicateStage(
+                    stage_config.label.clone(),
+                ));
+            }
+            let stage = self.build_stage(stage_config)?;
+            schedule.add_boxed_stage(label, Box::new(stage));
+        }
+        Ok(schedule)
+    }
+
+    /// Builds a [`SystemStage`] containing the enabled systems of `config`.
+    ///
+    /// The stage always runs on the
+    /// [`SingleThreadedExecutor`](crate::schedule::SingleThreadedExecutor), which is the only
+    /// executor of this crate. Use [`SystemStage::set_executor`] to replace it.
+    pub fn build_stage(&self, config: &StageConfig) -> Result<SystemStage, ScheduleConfigError> {
+        let mut stage = SystemStage::single_threaded();
+        for state in config.state_drivers.iter() {
+            let registered = self.registered_state(state)?;
+            stage.add_system_set((registered.driver)());
+        }
+        for criteria_config in config.run_criteria.iter() {
+            let mut criteria = self.create_run_criteria(&criteria_config.name)?;
+            criteria.label = Some(self.run_criteria_label(&criteria_config.label));
+            criteria.duplicate_label_strategy = DuplicateLabelStrategy::Panic;
+            stage.add_system_run_criteria(criteria);
+        }
+        for system_config in config.systems.iter().filter(|system| system.enabled) {
+            stage.add_system_set(self.build_system(system_config)?);
+        }
+        Ok(stage)
+    }
+
+    fn build_system(&self, config: &SystemConfig) -> Result<SystemSet, ScheduleConfigError> {
+        let descriptor = self.create_system(&config.name)?;
+        let mut set = SystemSet::new();
+        if let Some(criteria_config) = &config.run_criteria {
+            let has_run_criteria = match &descriptor {
+                SystemDescriptor::Parallel(descriptor) => descriptor.run_criteria.is_some(),
+                SystemDescriptor::Exclusive(descriptor) => descriptor.run_criteria.is_some(),
+            };
+            if has_run_criteria {
+                return Err(ScheduleConfigError::ConflictingRunCriteria(
+                    config.name.clone(),
+                ));
+            }
+            set = set.with_run_criteria(self.build_run_criteria(criteria_config)?);
+        }
+        set = set.with_system(descriptor);
+        for label in config.labels.iter() {
+            set.labels.push(self.system_label(label));
+        }
+        for label in config.before.iter() {
+            set.before.push(self.system_label(label));
+        }
+        for label in config.after.iter() {
+            set.after.push(self.system_label(label));
+        }
+        Ok(set)
+    }
+
+    fn build_run_criteria(
+        &self,
+        config: &RunCriteriaConfig,
+    ) -> Result<RunCriteriaDescriptorOrLabel, ScheduleConfigError> {
+        Ok(match config {
+            RunCriteriaConfig::Named(name) => {
+                RunCriteriaDescriptorOrLabel::Descriptor(self.create_run_criteria(name)?)
+            }
+            RunCriteriaConfig::Label(label) => {
+                RunCriteriaDescriptorOrLabel::Label(self.run_criteria_label(label))
+            }
+            RunCriteriaConfig::State { state, on } => {
+                let registered = self.registered_state(state)?;
+                RunCriteriaDescriptorOrLabel::Descriptor((registered.run_criteria)(*on))
+            }
+        })
+    }
+
+    fn registered_state(&self, name: &str) -> Result<&RegisteredState, ScheduleConfigError> {
+        self.states
+            .get(name)
+            .ok_or_else(|| ScheduleConfigError::UnknownState(name.to_string()))
+    }
+
+    fn stage_label(&self, name: &str) -> BoxedStageLabel {
+        self.stage_labels
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| Box::new(Cow::<'static, str>::Owned(name.to_string())))
+    }
+
+    fn system_label(&self, name: &str) -> BoxedSystemLabel {
+        self.system_labels
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| Box::new(Cow::<'static, str>::Owned(name.to_string())))
+    }
+
+    fn run_criteria_label(&self, name: &str) -> BoxedRunCriteriaLabel {
+        self.run_criteria_labels
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| Box::new(Cow::<'static, str>::Owned(name.to_string())))
+    }
+}
+
+/// An error that occurs when building a [`Schedule`] from a [`ScheduleConfig`].
+#[derive(Debug, Error)]
+pub enum ScheduleConfigError {
+    #[error("No system is registered under the name `{0}`.")]
+    UnknownSystem(String),
+    #[error("No run criteria is registered under the name `{0}`.")]
+    UnknownRunCriteria(String),
+    #[error("No state is registered under the name `{0}`.")]
+    UnknownState(String),
+    #[error("The stage `{0}` is defined more than once, possibly under another name.")]
+    DuplicateStage(String),
+    #[error(
+        "The system `{0}` already has a run criteria, so the configured one cannot be applied."
+    )]
+    ConflictingRunCriteria(String),
+}
+
+/// A data description of a [`Schedule`], built with [`SystemRegistry::build_schedule`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScheduleConfig {
+    /// The stages of the schedule, in execution order.
+    pub stages: Vec<StageConfig>,
+}
+
+/// A data description of a [`SystemStage`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StageConfig {
+    /// The name of a registered stage label, or a string label.
+    pub label: String,
+    /// Registered state values whose [`State`] type is driven by this stage, see
+    /// [`State::get_driver`]. At most one value per state type should be listed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub state_drivers: Vec<String>,
+    /// Labeled run criteria that systems of this stage can share via [`RunCriteriaConfig::Label`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub run_criteria: Vec<LabeledRunCriteriaConfig>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub systems: Vec<SystemConfig>,
+}
+
+/// A registered run criteria added to a stage under a label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabeledRunCriteriaConfig {
+    /// The name of a registered run criteria label, or a string label.
+    pub label: String,
+    /// The name of a registered run criteria.
+    pub name: String,
+}
+
+/// A data description of a system and when it runs within its stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SystemConfig {
+    /// The name of a registered system.
+    pub name: String,
+    /// Disabled systems are left out of the stage.
+    #[cfg_attr(feature = "serde", serde(default = "enabled_by_default"))]
+    pub enabled: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub labels: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub before: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub after: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub run_criteria: Option<RunCriteriaConfig>,
+}
+
+impl SystemConfig {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            enabled: true,
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+            run_criteria: None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// A data description of the run criteria of a system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RunCriteriaConfig {
+    /// A new instance of the run criteria registered under this name.
+    Named(String),
+    /// The run criteria with this label, defined elsewhere in the stage.
+    Label(String),
+    /// The run criteria of a [`State`] callback for the state value registered as `state`.
+    State {
+        state: String,
+        on: StateCallbackConfig,
+    },
+}
+
+/// The [`State`] callback a [`RunCriteriaConfig::State`] is driven by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StateCallbackConfig {
+    Update,
+    InactiveUpdate,
+    InStackUpdate,
+    Enter,
+    Exit,
+    Pause,
+    Resume,
+}
@@ -1,12 +1,12 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration},
-    component::ComponentId,
+    component::{ComponentId, Tick},
     query::Access,
     schedule::{BoxedRunCriteriaLabel, GraphNode, RunCriteriaLabel},
     system::{BoxedSystem, IntoSystem, System},
     world::World,
 };
-use std::borrow::Cow;
+use std::{borrow::Cow, marker::PhantomData, ops::Not};
 
 /// Determines whether a system should be executed or not, and how many times it should be ran each
 /// time the stage is executed.
@@ -44,6 +44,54 @@ pub enum ShouldRun {
     NoAndCheckAgain,
 }
 
+impl From<bool> for ShouldRun {
+    /// Converts `true` to [`ShouldRun::Yes`] and `false` to [`ShouldRun::No`].
+    fn from(should_run: bool) -> Self {
+        if should_run {
+            ShouldRun::Yes
+        } else {
+            ShouldRun::No
+        }
+    }
+}
+
+impl Not for ShouldRun {
+    type Output = ShouldRun;
+
+    /// Inverts whether the system should run, keeping whether the criteria should be checked
+    /// again.
+    fn not(self) -> ShouldRun {
+        match self {
+            ShouldRun::Yes => ShouldRun::No,
+            ShouldRun::No => ShouldRun::Yes,
+            ShouldRun::YesAndCheckAgain => ShouldRun::NoAndCheckAgain,
+            ShouldRun::NoAndCheckAgain => ShouldRun::YesAndCheckAgain,
+        }
+    }
+}
+
+impl ShouldRun {
+    fn from_parts(should_run: bool, check_again: bool) -> Self {
+        match (should_run, check_again) {
+            (true, false) => ShouldRun::Yes,
+            (false, false) => ShouldRun::No,
+            (true, true) => ShouldRun::YesAndCheckAgain,
+            (false, true) => ShouldRun::NoAndCheckAgain,
+        }
+    }
+
+    fn should_run(self) -> bool {
+        matches!(self, ShouldRun::Yes | ShouldRun::YesAndCheckAgain)
+    }
+
+    fn check_again(self) -> bool {
+        matches!(
+            self,
+            ShouldRun::YesAndCheckAgain | ShouldRun::NoAndCheckAgain
+        )
+    }
+}
+
 pub(crate) struct BoxedRunCriteria {
     criteria_system: Option<BoxedSystem<(), ShouldRun>>,
     initialized: bool,
@@ -300,7 +348,9 @@ impl RunCriteriaDescriptorCoercion<()> for RunCriteriaDescriptor {
     }
 }
 
-fn new_run_criteria_descriptor(system: BoxedSystem<(), ShouldRun>) -> RunCriteriaDescriptor {
+pub(crate) fn new_run_criteria_descriptor(
+    system: BoxedSystem<(), ShouldRun>,
+) -> RunCriteriaDescriptor {
     RunCriteriaDescriptor {
         system: RunCriteriaSystem::Single(system),
         label: None,
@@ -396,6 +446,314 @@ where
     }
 }
 
+impl RunCriteriaDescriptor {
+    /// Combines this criteria with `other`, so that systems only run if both criteria say so.
+    ///
+    /// The criteria are checked again while either of them asks for it, unless one of them
+    /// returned [`ShouldRun::No`].
+    ///
+    /// The labels of the combined criteria are discarded, but their ordering constraints are kept.
+    /// Both criteria can be piped, as long as they are piped from the same criteria.
+    pub fn and(self, other: RunCriteriaDescriptor) -> RunCriteriaDescriptor {
+        self.combine(other, RunCriteriaOperator::And)
+    }
+
+    /// Combines this criteria with `other`, so that systems run if either criteria says so.
+    ///
+    /// The criteria are checked again while either of them asks for it.
+    ///
+    /// The labels of the combined criteria are discarded, but their ordering constraints are kept.
+    /// Both criteria can be piped, as long as they are piped from the same criteria.
+    pub fn or(self, other: RunCriteriaDescriptor) -> RunCriteriaDescriptor {
+        self.combine(other, RunCriteriaOperator::Or)
+    }
+
+    fn combine(
+        mut self,
+        mut other: RunCriteriaDescriptor,
+        operator: RunCriteriaOperator,
+    ) -> RunCriteriaDescriptor {
+        // The input of a piped criteria comes from the criteria it runs after first.
+        match (&self.system, &other.system) {
+            (RunCriteriaSystem::Piped(_), RunCriteriaSystem::Piped(_)) => {
+                assert!(
+                    *self.after[0] == *other.after[0],
+                    "Can't combine run criteria piped from different criteria ({:?} and {:?}).",
+                    self.after[0],
+                    other.after[0]
+                );
+            }
+            (RunCriteriaSystem::Single(_), RunCriteriaSystem::Piped(_)) => {
+                std::mem::swap(&mut self.after, &mut other.after);
+            }
+            _ => {}
+        }
+        let mut after = self.after;
+        after.extend(other.after);
+        let mut before = self.before;
+        before.extend(other.before);
+        let system = CombinedRunCriteria::new_system(self.system, Some((operator, other.system)));
+        RunCriteriaDescriptor {
+            system,
+            label: None,
+            duplicate_label_strategy: DuplicateLabelStrategy::Panic,
+            before,
+            after,
+        }
+    }
+}
+
+impl Not for RunCriteriaDescriptor {
+    type Output = RunCriteriaDescriptor;
+
+    /// Inverts this criteria, so that systems only run if it says they shouldn't.
+    ///
+    /// The label of the criteria is discarded, but its ordering constraints are kept.
+    fn not(self) -> RunCriteriaDescriptor {
+        RunCriteriaDescriptor {
+            system: CombinedRunCriteria::new_system(self.system, None),
+            label: None,
+            duplicate_label_strategy: DuplicateLabelStrategy::Panic,
+            before: self.before,
+            after: self.after,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum RunCriteriaOperator {
+    And,
+    Or,
+}
+
+impl RunCriteriaSystem {
+    fn system_name(&self) -> Cow<'static, str> {
+        match self {
+            RunCriteriaSystem::Single(system) => system.name(),
+            RunCriteriaSystem::Piped(system) => system.name(),
+        }
+    }
+
+    fn system(&self) -> &dyn RunCriteriaSystemMethods {
+        match self {
+            RunCriteriaSystem::Single(system) => system,
+            RunCriteriaSystem::Piped(system) => system,
+        }
+    }
+
+    fn system_mut(&mut self) -> &mut dyn RunCriteriaSystemMethods {
+        match self {
+            RunCriteriaSystem::Single(system) => system,
+            RunCriteriaSystem::Piped(system) => system,
+        }
+    }
+
+    /// # Safety
+    /// See [`System::run_unsafe`].
+    unsafe fn run_unsafe(&mut self, input: Option<ShouldRun>, world: &World) -> ShouldRun {
+        match self {
+            RunCriteriaSystem::Single(system) => system.run_unsafe((), world),
+            RunCriteriaSystem::Piped(system) => system.run_unsafe(
+                input.expect("piped run criteria were combined into a criteria without input"),
+                world,
+            ),
+        }
+    }
+}
+
+/// The [`System`] methods that don't depend on the system's input.
+trait RunCriteriaSystemMethods {
+    fn new_archetype(&mut self, archetype: &Archetype);
+    fn component_access(&self) -> &Access<ComponentId>;
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId>;
+    fn is_send(&self) -> bool;
+    fn apply_buffers(&mut self, world: &mut World);
+    fn initialize(&mut self, world: &mut World);
+    fn check_change_tick(&mut self, change_tick: Tick);
+}
+
+impl<In: 'static> RunCriteriaSystemMethods for BoxedSystem<In, ShouldRun> {
+    fn new_archetype(&mut self, archetype: &Archetype) {
+        System::new_archetype(&mut **self, archetype);
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        System::component_access(&**self)
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        System::archetype_component_access(&**self)
+    }
+
+    fn is_send(&self) -> bool {
+        System::is_send(&**self)
+    }
+
+    fn apply_buffers(&mut self, world: &mut World) {
+        System::apply_buffers(&mut **self, world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        System::initialize(&mut **self, world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        System::check_change_tick(&mut **self, change_tick);
+    }
+}
+
+/// The input of a [`CombinedRunCriteria`]: `()` if none of the combined criteria are piped, and
+/// [`ShouldRun`] otherwise.
+trait CombinedRunCriteriaInput: Send + Sync + 'static {
+    fn into_piped(self) -> Option<ShouldRun>;
+}
+
+impl CombinedRunCriteriaInput for () {
+    fn into_piped(self) -> Option<ShouldRun> {
+        None
+    }
+}
+
+impl CombinedRunCriteriaInput for ShouldRun {
+    fn into_piped(self) -> Option<ShouldRun> {
+        Some(self)
+    }
+}
+
+/// A run criteria combining the result of another criteria with a second criteria, or inverting
+/// it if there is no second criteria.
+struct CombinedRunCriteria<In> {
+    left: RunCriteriaSystem,
+    right: Option<(RunCriteriaOperator, RunCriteriaSystem)>,
+    name: Cow<'static, str>,
+    component_access: Access<ComponentId>,
+    archetype_component_access: Access<ArchetypeComponentId>,
+    marker: PhantomData<fn(In)>,
+}
+
+impl CombinedRunCriteria<()> {
+    fn new_system(
+        left: RunCriteriaSystem,
+        right: Option<(RunCriteriaOperator, RunCriteriaSystem)>,
+    ) -> RunCriteriaSystem {
+        let name = match &right {
+            Some((RunCriteriaOperator::And, right)) => {
+                format!("And({}, {})", left.system_name(), right.system_name())
+            }
+            Some((RunCriteriaOperator::Or, right)) => {
+                format!("Or({}, {})", left.system_name(), right.system_name())
+            }
+            None => format!("Not({})", left.system_name()),
+        };
+        let is_piped = matches!(left, RunCriteriaSystem::Piped(_))
+            || matches!(right, Some((_, RunCriteriaSystem::Piped(_))));
+        if is_piped {
+            RunCriteriaSystem::Piped(Box::new(CombinedRunCriteria::<ShouldRun> {
+                left,
+                right,
+                name: name.into(),
+                component_access: Default::default(),
+                archetype_component_access: Default::default(),
+                marker: PhantomData,
+            }))
+        } else {
+            RunCriteriaSystem::Single(Box::new(CombinedRunCriteria::<()> {
+                left,
+                right,
+                name: name.into(),
+                component_access: Default::default(),
+                archetype_component_access: Default::default(),
+                marker: PhantomData,
+            }))
+        }
+    }
+}
+
+impl<In: CombinedRunCriteriaInput> System for CombinedRunCriteria<In> {
+    type In = In;
+    type Out = ShouldRun;
+
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+
+    fn new_archetype(&mut self, archetype: &Archetype) {
+        for criteria in
+            std::iter::once(&mut self.left).chain(self.right.as_mut().map(|(_, right)| right))
+        {
+            let system = criteria.system_mut();
+            system.new_archetype(archetype);
+            self.archetype_component_access
+                .extend(system.archetype_component_access());
+        }
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        &self.component_access
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        &self.archetype_component_access
+    }
+
+    fn is_send(&self) -> bool {
+        self.left.system().is_send()
+            && self
+                .right
+                .as_ref()
+                .is_none_or(|(_, right)| right.system().is_send())
+    }
+
+    unsafe fn run_unsafe(&mut self, input: In, world: &World) -> ShouldRun {
+        let input = input.into_piped();
+        let left = self.left.run_unsafe(input, world);
+        let (operator, right) = match &mut self.right {
+            Some((operator, right)) => (*operator, right.run_unsafe(input, world)),
+            None => return !left,
+        };
+        match operator {
+            RunCriteriaOperator::And => {
+                if left == ShouldRun::No || right == ShouldRun::No {
+                    ShouldRun::No
+                } else {
+                    ShouldRun::from_parts(
+                        left.should_run() && right.should_run(),
+                        left.check_again() || right.check_again(),
+                    )
+                }
+            }
+            RunCriteriaOperator::Or => ShouldRun::from_parts(
+                left.should_run() || right.should_run(),
+                left.check_again() || right.check_again(),
+            ),
+        }
+    }
+
+    fn apply_buffers(&mut self, world: &mut World) {
+        self.left.system_mut().apply_buffers(world);
+        if let Some((_, right)) = &mut self.right {
+            right.system_mut().apply_buffers(world);
+        }
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        for criteria in
+            std::iter::once(&mut self.left).chain(self.right.as_mut().map(|(_, right)| right))
+        {
+            let system = criteria.system_mut();
+            system.initialize(world);
+            self.component_access.extend(system.component_access());
+        }
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.left.system_mut().check_change_tick(change_tick);
+        if let Some((_, right)) = &mut self.right {
+            right.system_mut().check_change_tick(change_tick);
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct RunOnce {
     ran: bool,
@@ -438,5 +796,5 @@ impl System for RunOnce {
 
     fn initialize(&mut self, _world: &mut World) {}
 
-    fn check_change_tick(&mut self, _change_tick: u32) {}
+    fn check_change_tick(&mut self, _change_tick: Tick) {}
 }
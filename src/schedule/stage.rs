@@ -1,5 +1,5 @@
 use crate::{
-    component::ComponentId,
+    component::{ComponentId, Tick},
     prelude::IntoSystem,
     schedule::{
         graph_utils::{self, DependencyGraphError},
@@ -83,7 +83,7 @@ pub struct SystemStage {
     /// Newly inserted systems that will be initialized at the next opportunity.
     uninitialized_parallel: Vec<usize>,
     /// Saves the value of the World change_tick during the last tick check
-    last_tick_check: u32,
+    last_tick_check: Tick,
     /// If true, buffers will be automatically applied at the end of the stage. If false, buffers must be manually applied.
     apply_buffers: bool,
 }
@@ -555,12 +555,16 @@ impl SystemStage {
 
     /// Checks for old component and system change ticks
     fn check_change_ticks(&mut self, world: &mut World) {
+        // 64-bit ticks can't realistically wrap around, so they never need to be clamped
+        if cfg!(feature = "u64_ticks") {
+            return;
+        }
         let change_tick = world.change_tick();
         let time_since_last_check = change_tick.wrapping_sub(self.last_tick_check);
-        // Only check after at least `u32::MAX / 8` counts, and at most `u32::MAX / 4` counts
-        // since the max number of [System] in a [SystemStage] is limited to `u32::MAX / 8`
+        // Only check after at least `Tick::MAX / 8` counts, and at most `Tick::MAX / 4` counts
+        // since the max number of [System] in a [SystemStage] is limited to `Tick::MAX / 8`
         // and this function is called at the end of each [SystemStage] loop
-        const MIN_TIME_SINCE_LAST_CHECK: u32 = u32::MAX / 8;
+        const MIN_TIME_SINCE_LAST_CHECK: Tick = Tick::MAX / 8;
 
         if time_since_last_check > MIN_TIME_SINCE_LAST_CHECK {
             // Check all system change ticks
@@ -873,6 +877,8 @@ impl Stage for SystemStage {
                             container.system_mut().apply_buffers(world);
                         }
                     }
+                    // Apply the async tasks that completed, including those spawned just now.
+                    crate::task::poll_tasks(world);
                 }
 
                 // Run systems that want to be at the end of stage.
@@ -1,3 +1,4 @@
+use crate::world::MemoryUsage;
 use std::{
     alloc::{handle_alloc_error, Layout},
     ptr::NonNull,
@@ -58,6 +59,18 @@ impl BlobVec {
         self.capacity
     }
 
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        let size = self.item_layout.size();
+        if size == 0 {
+            return MemoryUsage::default();
+        }
+        MemoryUsage {
+            used: self.len * size,
+            // The swap scratch holds one more item
+            reserved: (self.capacity + 1) * size,
+        }
+    }
+
     pub fn reserve_exact(&mut self, additional: usize) {
         let available_space = self.capacity - self.len;
         if available_space < additional {
@@ -65,6 +78,29 @@ impl BlobVec {
         }
     }
 
+    /// Releases the unused capacity of this vec.
+    pub fn shrink_to_fit(&mut self) {
+        if self.item_layout.size() == 0 || self.capacity == self.len {
+            return;
+        }
+        let old_layout =
+            array_layout(&self.item_layout, self.capacity).expect("array layout should be valid");
+        unsafe {
+            if self.len == 0 {
+                std::alloc::dealloc(self.get_ptr().as_ptr(), old_layout);
+                self.data = NonNull::dangling();
+            } else {
+                let new_layout = array_layout(&self.item_layout, self.len)
+                    .expect("array layout should be valid");
+                let new_data =
+                    std::alloc::realloc(self.get_ptr().as_ptr(), old_layout, new_layout.size());
+                self.data =
+                    NonNull::new(new_data).unwrap_or_else(|| handle_alloc_error(new_layout));
+            }
+        }
+        self.capacity = self.len;
+    }
+
     fn grow_exact(&mut self, increment: usize) {
         debug_assert!(self.item_layout.size() != 0);
 
@@ -224,6 +260,10 @@ impl Drop for BlobVec {
         if array_layout.size() > 0 {
             unsafe {
                 std::alloc::dealloc(self.get_ptr().as_ptr(), array_layout);
+            }
+        }
+        if self.item_layout.size() > 0 {
+            unsafe {
                 std::alloc::dealloc(self.swap_scratch.as_ptr(), self.item_layout);
             }
         }
@@ -1,7 +1,8 @@
 use crate::{
-    component::{ComponentId, ComponentInfo, ComponentTicks},
+    component::{ComponentId, ComponentInfo, ComponentTicks, Tick},
     entity::Entity,
     storage::BlobVec,
+    world::MemoryUsage,
 };
 use std::{cell::UnsafeCell, marker::PhantomData};
 
@@ -35,6 +36,22 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         }
     }
 
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.values.iter().flatten()
+    }
+
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.values)
+    }
+
+    /// Releases the unused capacity of this array, including the empty slots at its end.
+    pub fn shrink_to_fit(&mut self) {
+        while let Some(None) = self.values.last() {
+            self.values.pop();
+        }
+        self.values.shrink_to_fit();
+    }
+
     #[inline]
     pub fn insert(&mut self, index: I, value: V) {
         let index = index.sparse_set_index();
@@ -138,7 +155,7 @@ impl ComponentSparseSet {
     ///   sparse set.
     /// * The caller is responsible for ensuring it does not drop `*value` after
     ///   calling `insert`.
-    pub unsafe fn insert(&mut self, entity: Entity, value: *mut u8, change_tick: u32) {
+    pub unsafe fn insert(&mut self, entity: Entity, value: *mut u8, change_tick: Tick) {
         if let Some(&dense_index) = self.sparse.get(entity) {
             self.dense.replace_unchecked(dense_index, value);
             *self.ticks.get_unchecked_mut(dense_index) =
@@ -224,11 +241,26 @@ impl ComponentSparseSet {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for component_ticks in &mut self.ticks {
             component_ticks.get_mut().check_ticks(change_tick);
         }
     }
+
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        self.dense.memory_usage()
+            + MemoryUsage::of_vec(&self.ticks)
+            + MemoryUsage::of_vec(&self.entities)
+            + self.sparse.memory_usage()
+    }
+
+    /// Releases the unused capacity of this sparse set.
+    pub fn shrink_to_fit(&mut self) {
+        self.dense.shrink_to_fit();
+        self.ticks.shrink_to_fit();
+        self.entities.shrink_to_fit();
+        self.sparse.shrink_to_fit();
+    }
 }
 
 /// A data structure that blends dense and sparse storage
@@ -374,6 +406,20 @@ impl<I: SparseSetIndex, V> SparseSet<I, V> {
     pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
         self.dense.iter_mut()
     }
+
+    /// Releases the unused capacity of this set, not including the capacity owned by its values.
+    pub fn shrink_to_fit(&mut self) {
+        self.dense.shrink_to_fit();
+        self.indices.shrink_to_fit();
+        self.sparse.shrink_to_fit();
+    }
+
+    /// Returns the memory used by this set, not counting the memory owned by its values.
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.dense)
+            + MemoryUsage::of_vec(&self.indices)
+            + self.sparse.memory_usage()
+    }
 }
 
 pub trait SparseSetIndex: Clone {
@@ -425,15 +471,26 @@ impl SparseSets {
         self.sets.get_mut(component_id)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &ComponentSparseSet)> {
+        self.sets.indices().zip(self.sets.values())
+    }
+
     pub fn clear(&mut self) {
         for set in self.sets.values_mut() {
             set.clear();
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for set in self.sets.values_mut() {
             set.check_change_ticks(change_tick);
         }
     }
+
+    /// Releases the unused capacity of all sparse sets.
+    pub fn shrink_to_fit(&mut self) {
+        for set in self.sets.values_mut() {
+            set.shrink_to_fit();
+        }
+    }
 }
\ No newline at end of file
@@ -1,9 +1,11 @@
 use crate::{
-    component::{ComponentId, ComponentInfo, ComponentTicks, Components},
+    component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick},
     entity::Entity,
     storage::{BlobVec, SparseSet},
+    world::MemoryUsage,
 };
 use bevy_utils::{AHasher, HashMap};
+use fixedbitset::FixedBitSet;
 use std::{
     cell::UnsafeCell,
     hash::{Hash, Hasher},
@@ -66,7 +68,7 @@ impl Column {
     /// # Safety
     /// Assumes data has already been allocated for the given row.
     #[inline]
-    pub unsafe fn replace(&mut self, row: usize, data: *mut u8, change_tick: u32) {
+    pub unsafe fn replace(&mut self, row: usize, data: *mut u8, change_tick: Tick) {
         debug_assert!(row < self.len());
         self.data.replace_unchecked(row, data);
         self.ticks
@@ -185,11 +187,21 @@ impl Column {
     }
 
     #[inline]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for component_ticks in &mut self.ticks {
             component_ticks.get_mut().check_ticks(change_tick);
         }
     }
+
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        self.data.memory_usage() + MemoryUsage::of_vec(&self.ticks)
+    }
+
+    /// Releases the unused capacity of this column.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.ticks.shrink_to_fit();
+    }
 }
 
 pub struct Table {
@@ -242,6 +254,24 @@ impl Table {
         }
     }
 
+    /// Removes the entity at the given row like [`Table::swap_remove_unchecked`], but "forgets"
+    /// its column values instead of dropping them. It is the caller's responsibility to drop them
+    ///
+    /// # Safety
+    /// `row` must be in-bounds
+    pub(crate) unsafe fn swap_remove_and_forget_unchecked(&mut self, row: usize) -> Option<Entity> {
+        for column in self.columns.values_mut() {
+            column.swap_remove_and_forget_unchecked(row);
+        }
+        let is_last = row == self.entities.len() - 1;
+        self.entities.swap_remove(row);
+        if is_last {
+            None
+        } else {
+            Some(self.entities[row])
+        }
+    }
+
     /// Moves the `row` column values to `new_table`, for the columns shared between both tables.
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in). missing columns will be "forgotten". It is
@@ -392,12 +422,25 @@ impl Table {
         self.entities.is_empty()
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for column in self.columns.values_mut() {
             column.check_change_ticks(change_tick);
         }
     }
 
+    /// Releases the unused capacity of this table and its columns.
+    pub fn shrink_to_fit(&mut self) {
+        self.entities.shrink_to_fit();
+        for column in self.columns.values_mut() {
+            column.shrink_to_fit();
+        }
+    }
+
+    /// Returns the memory used by this table, not counting the memory used by its columns.
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.entities) + self.columns.memory_usage()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Column> {
         self.columns.values()
     }
@@ -416,6 +459,9 @@ impl Table {
 pub struct Tables {
     tables: Vec<Table>,
     table_ids: HashMap<u64, TableId>,
+    /// The tables removed by [`Tables::remove`]. Their slots are kept so that table ids stay
+    /// stable.
+    removed: FixedBitSet,
 }
 
 impl Default for Tables {
@@ -424,6 +470,7 @@ impl Default for Tables {
         Tables {
             tables: vec![empty_table],
             table_ids: HashMap::default(),
+            removed: FixedBitSet::default(),
         }
     }
 }
@@ -434,6 +481,7 @@ pub struct TableMoveResult {
 }
 
 impl Tables {
+    /// Returns the number of table ids handed out so far, including those of removed tables.
     #[inline]
     pub fn len(&self) -> usize {
         self.tables.len()
@@ -444,13 +492,25 @@ impl Tables {
         self.tables.is_empty()
     }
 
+    /// Returns `true` if the table with the given id was removed by [`Tables::remove`].
+    #[inline]
+    pub fn is_removed(&self, id: TableId) -> bool {
+        self.removed.contains(id.index())
+    }
+
     #[inline]
     pub fn get(&self, id: TableId) -> Option<&Table> {
+        if self.is_removed(id) {
+            return None;
+        }
         self.tables.get(id.index())
     }
 
     #[inline]
     pub fn get_mut(&mut self, id: TableId) -> Option<&mut Table> {
+        if self.is_removed(id) {
+            return None;
+        }
         self.tables.get_mut(id.index())
     }
 
@@ -486,12 +546,39 @@ impl Tables {
         })
     }
 
-    pub fn iter(&self) -> std::slice::Iter<'_, Table> {
-        self.tables.iter()
-    }
-
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Table> {
-        self.tables.iter_mut()
+    /// Removes the given table, which must be empty, along with its columns. Its id is never
+    /// handed out again: a table with the same components gets a new id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is the empty table, which always exists.
+    pub(crate) fn remove(&mut self, id: TableId) {
+        assert_ne!(id, TableId::empty(), "the empty table can't be removed");
+        debug_assert!(self.tables[id.index()].is_empty());
+        self.tables[id.index()] = Table::new();
+        self.table_ids.retain(|_, table_id| *table_id != id);
+        self.removed.grow(id.index() + 1);
+        self.removed.insert(id.index());
+    }
+
+    /// Iterates over the tables that were not removed.
+    pub fn iter(&self) -> impl Iterator<Item = &Table> {
+        let removed = &self.removed;
+        self.tables
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| !removed.contains(*index))
+            .map(|(_, table)| table)
+    }
+
+    /// Iterates mutably over the tables that were not removed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Table> {
+        let removed = &self.removed;
+        self.tables
+            .iter_mut()
+            .enumerate()
+            .filter(move |(index, _)| !removed.contains(*index))
+            .map(|(_, table)| table)
     }
 
     pub fn clear(&mut self) {
@@ -500,11 +587,18 @@ impl Tables {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for table in self.tables.iter_mut() {
             table.check_change_ticks(change_tick);
         }
     }
+
+    /// Releases the unused capacity of all tables.
+    pub fn shrink_to_fit(&mut self) {
+        for table in self.tables.iter_mut() {
+            table.shrink_to_fit();
+        }
+    }
 }
 
 impl Index<TableId> for Tables {
@@ -0,0 +1,81 @@
+use crate::{entity::Entity, event::Events, world::World};
+use bevy_utils::tracing::warn;
+use thiserror::Error;
+
+/// A [`World`] mutation that can fail.
+///
+/// Fallible commands are pushed with [`CommandQueue::push_fallible`](super::CommandQueue::push_fallible)
+/// or [`Commands::add_fallible`](super::Commands::add_fallible). Any error they return is handed to
+/// the world's [`CommandErrorHandler`] when the queue is applied.
+pub trait FallibleCommand: Send + Sync + 'static {
+    fn try_write(self, world: &mut World) -> Result<(), CommandError>;
+}
+
+/// An error returned by a [`FallibleCommand`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("command `{command}` failed for entity {entity:?}: {kind}")]
+pub struct CommandError {
+    /// The type name of the command that failed.
+    pub command: &'static str,
+    /// The entity the command was applied to.
+    pub entity: Entity,
+    pub kind: CommandErrorKind,
+}
+
+impl CommandError {
+    /// Creates an error for the command type `C`.
+    pub fn new<C: ?Sized>(entity: Entity, kind: CommandErrorKind) -> Self {
+        Self {
+            command: std::any::type_name::<C>(),
+            entity,
+            kind,
+        }
+    }
+}
+
+/// The reason a [`FallibleCommand`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum CommandErrorKind {
+    #[error("the entity does not exist in this World")]
+    NoSuchEntity,
+    #[error("the entity does not have the component `{0}`")]
+    MissingComponent(&'static str),
+}
+
+/// Resource that decides what happens when a [`FallibleCommand`] fails.
+///
+/// If the resource is not present, [`CommandErrorHandler::Warn`] is used.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CommandErrorHandler {
+    /// Panic with the error message.
+    Panic,
+    /// Log the error as a warning and continue.
+    #[default]
+    Warn,
+    /// Silently discard the error.
+    Ignore,
+    /// Send the error to the [`Events<CommandError>`] resource. Falls back to
+    /// [`CommandErrorHandler::Warn`] if that resource does not exist.
+    SendEvent,
+}
+
+impl CommandErrorHandler {
+    /// Handles `error` according to the [`CommandErrorHandler`] resource of `world`.
+    pub fn handle(world: &mut World, error: CommandError) {
+        let handler = world
+            .get_resource::<CommandErrorHandler>()
+            .copied()
+            .unwrap_or_default();
+        match handler {
+            CommandErrorHandler::Panic => panic!("{}", error),
+            CommandErrorHandler::Warn => warn!("{}", error),
+            CommandErrorHandler::Ignore => {}
+            CommandErrorHandler::SendEvent => {
+                match world.get_resource_mut::<Events<CommandError>>() {
+                    Some(mut events) => events.send(error),
+                    None => warn!("{}", error),
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use super::{Command, Commands};
+use crate::{
+    entity::{Entity, EntityMap, MapEntitiesError},
+    system::ResMut,
+    world::World,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A [`Command`] that can be recorded into a [`CommandLog`], serialized and replayed later.
+///
+/// Commands that should be replayed together are usually collected in a single enum that
+/// implements this trait and dispatches to the individual commands.
+pub trait SerializableCommand: Command + Clone + Serialize + DeserializeOwned {
+    /// Replaces every [`Entity`] referenced by this command with `map(entity)`.
+    ///
+    /// This is called before a command is replayed, so that entity ids from the recorded world
+    /// can be translated into ids of the world the command is replayed into.
+    fn map_entities(&mut self, map: &mut dyn FnMut(Entity) -> Entity);
+
+    /// Returns the entities spawned by this command in the recorded world.
+    ///
+    /// When replaying, each of these entities that is not in the entity map yet is spawned in the
+    /// target world before the command runs. Commands that spawn entities must override this.
+    fn spawned_entities(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+}
+
+/// Resource that records the [`SerializableCommand`]s of type `C`, grouped per frame.
+///
+/// Commands are recorded when they are applied, if they were added with
+/// [`Commands::add_recorded`] and a `CommandLog<C>` resource exists. Call
+/// [`next_frame`](Self::next_frame) (or add [`next_frame_system`](Self::next_frame_system))
+/// once per frame to start a new frame.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandLog<C> {
+    frames: Vec<Vec<C>>,
+}
+
+impl<C> Default for CommandLog<C> {
+    fn default() -> Self {
+        Self {
+            frames: vec![Vec::new()],
+        }
+    }
+}
+
+impl<C: SerializableCommand> CommandLog<C> {
+    /// Appends `command` to the current frame.
+    pub fn record(&mut self, command: C) {
+        match self.frames.last_mut() {
+            Some(frame) => frame.push(command),
+            None => self.frames.push(vec![command]),
+        }
+    }
+
+    /// Ends the current frame and starts a new, empty one.
+    pub fn next_frame(&mut self) {
+        self.frames.push(Vec::new());
+    }
+
+    /// A system that calls [`CommandLog::next_frame`] once per frame.
+    pub fn next_frame_system(mut log: ResMut<Self>) {
+        log.next_frame();
+    }
+
+    /// Returns the recorded frames, including the current one.
+    pub fn frames(&self) -> &[Vec<C>] {
+        &self.frames
+    }
+
+    /// Returns the commands recorded during the current frame.
+    pub fn current_frame(&self) -> &[C] {
+        self.frames.last().map_or(&[], |frame| frame)
+    }
+
+    /// Removes all recorded commands.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.frames.push(Vec::new());
+    }
+
+    /// Replays every recorded frame into `world`, in order.
+    ///
+    /// See [`CommandLog::replay_frame`] for how entities are remapped.
+    pub fn replay(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityMap,
+    ) -> Result<(), MapEntitiesError> {
+        for frame in self.frames.iter() {
+            Self::replay_frame(frame, world, entity_map)?;
+        }
+        Ok(())
+    }
+
+    /// Replays `commands` into `world`.
+    ///
+    /// Every entity referenced by a command is translated with `entity_map`. The
+    /// [`spawned_entities`](SerializableCommand::spawned_entities) of a command that are not in
+    /// the map yet are spawned in `world` and added to the map, so later commands referencing
+    /// them resolve to the same entity.
+    ///
+    /// Returns an error for the first command referencing an entity that is neither in the map
+    /// nor spawned by the log. That command and the following ones are not applied.
+    pub fn replay_frame(
+        commands: &[C],
+        world: &mut World,
+        entity_map: &mut EntityMap,
+    ) -> Result<(), MapEntitiesError> {
+        for command in commands {
+            let mut command = command.clone();
+            for entity in command.spawned_entities() {
+                entity_map
+                    .entry(entity)
+                    .or_insert_with(|| world.spawn().id());
+            }
+            let mut result = Ok(());
+            command.map_entities(&mut |entity| match entity_map.get(entity) {
+                Ok(mapped) => mapped,
+                Err(err) => {
+                    if result.is_ok() {
+                        result = Err(err);
+                    }
+                    entity
+                }
+            });
+            result?;
+            command.write(world);
+        }
+        Ok(())
+    }
+}
+
+struct Recorded<C>(C);
+
+impl<C: SerializableCommand> Command for Recorded<C> {
+    fn write(self, world: &mut World) {
+        if let Some(mut log) = world.get_resource_mut::<CommandLog<C>>() {
+            log.record(self.0.clone());
+        }
+        self.0.write(world);
+    }
+}
+
+impl<'w, 's> Commands<'w, 's> {
+    /// Adds a [`SerializableCommand`] to the command queue.
+    ///
+    /// When the command is applied it is also recorded into the [`CommandLog<C>`] resource,
+    /// if one exists.
+    pub fn add_recorded<C: SerializableCommand>(&mut self, command: C) {
+        self.add(Recorded(command));
+    }
+}
\ No newline at end of file
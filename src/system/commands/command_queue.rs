@@ -1,9 +1,9 @@
-use super::Command;
+use super::{Command, CommandError, CommandErrorHandler, FallibleCommand};
 use crate::world::World;
 
 struct CommandMeta {
     offset: usize,
-    func: unsafe fn(value: *mut u8, world: &mut World),
+    func: unsafe fn(value: *mut u8, world: &mut World) -> Result<(), CommandError>,
 }
 
 /// A queue of [`Command`]s
@@ -35,17 +35,51 @@ impl CommandQueue {
         /// SAFE: This function is only every called when the `command` bytes is the associated
         /// [`Commands`] `T` type. Also this only reads the data via `read_unaligned` so unaligned
         /// accesses are safe.
-        unsafe fn write_command<T: Command>(command: *mut u8, world: &mut World) {
+        unsafe fn write_command<T: Command>(
+            command: *mut u8,
+            world: &mut World,
+        ) -> Result<(), CommandError> {
             let command = command.cast::<T>().read_unaligned();
             command.write(world);
+            Ok(())
         }
 
+        self.push_raw(command, write_command::<C>);
+    }
+
+    /// Push a [`FallibleCommand`] onto the queue.
+    ///
+    /// If the command fails when the queue is applied, the error is passed to the world's
+    /// [`CommandErrorHandler`].
+    #[inline]
+    pub fn push_fallible<C>(&mut self, command: C)
+    where
+        C: FallibleCommand,
+    {
+        /// SAFE: See `write_command` in [`CommandQueue::push`].
+        unsafe fn try_write_command<T: FallibleCommand>(
+            command: *mut u8,
+            world: &mut World,
+        ) -> Result<(), CommandError> {
+            let command = command.cast::<T>().read_unaligned();
+            command.try_write(world)
+        }
+
+        self.push_raw(command, try_write_command::<C>);
+    }
+
+    #[inline]
+    fn push_raw<C: Send + Sync + 'static>(
+        &mut self,
+        command: C,
+        func: unsafe fn(value: *mut u8, world: &mut World) -> Result<(), CommandError>,
+    ) {
         let size = std::mem::size_of::<C>();
         let old_len = self.bytes.len();
 
         self.metas.push(CommandMeta {
             offset: old_len,
-            func: write_command::<C>,
+            func,
         });
 
         if size > 0 {
@@ -71,6 +105,8 @@ impl CommandQueue {
 
     /// Execute the queued [`Command`]s in the world.
     /// This clears the queue.
+    ///
+    /// Errors returned by [`FallibleCommand`]s are handled by [`CommandErrorHandler::handle`].
     #[inline]
     pub fn apply(&mut self, world: &mut World) {
         // flush the previously queued entities
@@ -100,8 +136,9 @@ impl CommandQueue {
         for meta in self.metas.drain(..) {
             // SAFE: The implementation of `write_command` is safe for the according Command type.
             // The bytes are safely cast to their original type, safely read, and then dropped.
-            unsafe {
-                (meta.func)(byte_ptr.add(meta.offset), world);
+            let result = unsafe { (meta.func)(byte_ptr.add(meta.offset), world) };
+            if let Err(error) = result {
+                CommandErrorHandler::handle(world, error);
             }
         }
     }
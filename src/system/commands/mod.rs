@@ -1,3 +1,6 @@
+mod command_error;
+#[cfg(feature = "serde")]
+mod command_log;
 mod command_queue;
 
 use crate::{
@@ -7,6 +10,9 @@ use crate::{
     world::World,
 };
 use bevy_utils::tracing::{error, warn};
+pub use command_error::{CommandError, CommandErrorHandler, CommandErrorKind, FallibleCommand};
+#[cfg(feature = "serde")]
+pub use command_log::{CommandLog, SerializableCommand};
 pub use command_queue::CommandQueue;
 use std::marker::PhantomData;
 
@@ -17,6 +23,23 @@ pub trait Command: Send + Sync + 'static {
     fn write(self, world: &mut World);
 }
 
+/// A [`World`] mutation that targets a single [`Entity`].
+///
+/// Entity commands are queued with [`EntityCommands::add`], which supplies the entity id.
+/// Closures of the form `FnOnce(Entity, &mut World)` implement this trait.
+pub trait EntityCommand: Send + Sync + 'static {
+    fn write(self, entity: Entity, world: &mut World);
+}
+
+impl<F> EntityCommand for F
+where
+    F: FnOnce(Entity, &mut World) + Send + Sync + 'static,
+{
+    fn write(self, entity: Entity, world: &mut World) {
+        self(entity, world);
+    }
+}
+
 /// A list of commands that modify a [`World`], running at the end of the stage where they
 /// have been invoked.
 ///
@@ -350,6 +373,37 @@ impl<'w, 's> Commands<'w, 's> {
     pub fn add<C: Command>(&mut self, command: C) {
         self.queue.push(command);
     }
+
+    /// Adds a closure that mutates the [`World`] to the command queue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # struct Counter(u32);
+    /// #
+    /// fn reset_counter_system(mut commands: Commands) {
+    ///     commands.add_fn(|world| {
+    ///         world.insert_resource(Counter(0));
+    ///     });
+    /// }
+    /// # reset_counter_system.system();
+    /// ```
+    pub fn add_fn<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut World) + Send + Sync + 'static,
+    {
+        self.queue.push(FnCommand(f));
+    }
+
+    /// Adds a [`FallibleCommand`] to the command queue.
+    ///
+    /// If the command fails, the error is handled by the world's [`CommandErrorHandler`]
+    /// resource (which logs a warning by default).
+    pub fn add_fallible<C: FallibleCommand>(&mut self, command: C) {
+        self.queue.push_fallible(command);
+    }
 }
 
 /// A list of commands that will be run to modify an [entity](crate::entity).
@@ -547,12 +601,117 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         })
     }
 
+    /// Adds an [`EntityCommand`] that will be applied to this entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn heal_system(mut commands: Commands, query: Query<Entity, With<Health>>) {
+    ///     for entity in query.iter() {
+    ///         commands.entity(entity).add(|entity: Entity, world: &mut World| {
+    ///             if let Some(mut health) = world.get_mut::<Health>(entity) {
+    ///                 health.0 += 10;
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// # heal_system.system();
+    /// ```
+    pub fn add<C: EntityCommand>(&mut self, command: C) -> &mut Self {
+        self.commands.add(WithEntity {
+            entity: self.entity,
+            command,
+        });
+        self
+    }
+
+    /// Like [`insert_bundle`](Self::insert_bundle), but does not panic if the entity no longer
+    /// exists when the command is applied. The failure is passed to the [`CommandErrorHandler`].
+    pub fn try_insert_bundle(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.commands.add_fallible(InsertBundle {
+            entity: self.entity,
+            bundle,
+        });
+        self
+    }
+
+    /// Like [`insert`](Self::insert), but does not panic if the entity no longer exists when the
+    /// command is applied. The failure is passed to the [`CommandErrorHandler`].
+    pub fn try_insert(&mut self, component: impl Component) -> &mut Self {
+        self.commands.add_fallible(Insert {
+            entity: self.entity,
+            component,
+        });
+        self
+    }
+
+    /// Like [`remove_bundle`](Self::remove_bundle), but reports a missing entity to the
+    /// [`CommandErrorHandler`] instead of ignoring it.
+    pub fn try_remove_bundle<T>(&mut self) -> &mut Self
+    where
+        T: Bundle,
+    {
+        self.commands.add_fallible(RemoveBundle::<T> {
+            entity: self.entity,
+            phantom: PhantomData,
+        });
+        self
+    }
+
+    /// Like [`remove`](Self::remove), but reports a missing entity or a missing component to the
+    /// [`CommandErrorHandler`] instead of ignoring it.
+    pub fn try_remove<T>(&mut self) -> &mut Self
+    where
+        T: Component,
+    {
+        self.commands.add_fallible(Remove::<T> {
+            entity: self.entity,
+            phantom: PhantomData,
+        });
+        self
+    }
+
+    /// Like [`despawn`](Self::despawn), but reports a missing entity to the
+    /// [`CommandErrorHandler`] instead of always logging a warning.
+    pub fn try_despawn(&mut self) {
+        self.commands.add_fallible(Despawn {
+            entity: self.entity,
+        })
+    }
+
     /// Returns the underlying [`Commands`].
     pub fn commands(&mut self) -> &mut Commands<'w, 's> {
         self.commands
     }
 }
 
+struct FnCommand<F>(F);
+
+impl<F> Command for FnCommand<F>
+where
+    F: FnOnce(&mut World) + Send + Sync + 'static,
+{
+    fn write(self, world: &mut World) {
+        (self.0)(world);
+    }
+}
+
+struct WithEntity<C> {
+    entity: Entity,
+    command: C,
+}
+
+impl<C: EntityCommand> Command for WithEntity<C> {
+    fn write(self, world: &mut World) {
+        self.command.write(self.entity, world);
+    }
+}
+
 #[derive(Debug)]
 pub struct Spawn<T> {
     pub bundle: T,
@@ -636,6 +795,19 @@ impl Command for Despawn {
     }
 }
 
+impl FallibleCommand for Despawn {
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        if world.despawn(self.entity) {
+            Ok(())
+        } else {
+            Err(CommandError::new::<Self>(
+                self.entity,
+                CommandErrorKind::NoSuchEntity,
+            ))
+        }
+    }
+}
+
 pub struct InsertBundle<T> {
     pub entity: Entity,
     pub bundle: T,
@@ -656,6 +828,24 @@ where
     }
 }
 
+impl<T> FallibleCommand for InsertBundle<T>
+where
+    T: Bundle + 'static,
+{
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(mut entity) => {
+                entity.insert_bundle(self.bundle);
+                Ok(())
+            }
+            None => Err(CommandError::new::<Self>(
+                self.entity,
+                CommandErrorKind::NoSuchEntity,
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Insert<T> {
     pub entity: Entity,
@@ -677,6 +867,24 @@ where
     }
 }
 
+impl<T> FallibleCommand for Insert<T>
+where
+    T: Component,
+{
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(mut entity) => {
+                entity.insert(self.component);
+                Ok(())
+            }
+            None => Err(CommandError::new::<Self>(
+                self.entity,
+                CommandErrorKind::NoSuchEntity,
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Remove<T> {
     pub entity: Entity,
@@ -689,7 +897,30 @@ where
 {
     fn write(self, world: &mut World) {
         if let Some(mut entity_mut) = world.get_entity_mut(self.entity) {
-            entity_mut.remove::<T>();
+            if let Some(value) = entity_mut.remove::<T>() {
+                world.store_removed_value(self.entity, value);
+            }
+        }
+    }
+}
+
+impl<T> FallibleCommand for Remove<T>
+where
+    T: Component,
+{
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        let mut entity_mut = world.get_entity_mut(self.entity).ok_or_else(|| {
+            CommandError::new::<Self>(self.entity, CommandErrorKind::NoSuchEntity)
+        })?;
+        match entity_mut.remove::<T>() {
+            Some(value) => {
+                world.store_removed_value(self.entity, value);
+                Ok(())
+            }
+            None => Err(CommandError::new::<Self>(
+                self.entity,
+                CommandErrorKind::MissingComponent(std::any::type_name::<T>()),
+            )),
         }
     }
 }
@@ -713,6 +944,24 @@ where
     }
 }
 
+impl<T> FallibleCommand for RemoveBundle<T>
+where
+    T: Bundle,
+{
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(mut entity_mut) => {
+                entity_mut.remove_bundle_intersection::<T>();
+                Ok(())
+            }
+            None => Err(CommandError::new::<Self>(
+                self.entity,
+                CommandErrorKind::NoSuchEntity,
+            )),
+        }
+    }
+}
+
 pub struct InsertResource<T: Resource> {
     pub resource: T,
 }
@@ -1,5 +1,6 @@
 use crate::{
     archetype::ArchetypeGeneration,
+    component::Tick,
     system::{check_system_change_tick, BoxedSystem, IntoSystem},
     world::World,
 };
@@ -12,13 +13,13 @@ pub trait ExclusiveSystem: Send + Sync + 'static {
 
     fn initialize(&mut self, world: &mut World);
 
-    fn check_change_tick(&mut self, change_tick: u32);
+    fn check_change_tick(&mut self, change_tick: Tick);
 }
 
 pub struct ExclusiveSystemFn<F> {
     func: F,
     name: Cow<'static, str>,
-    last_change_tick: u32,
+    last_change_tick: Tick,
 }
 
 impl<F> ExclusiveSystem for ExclusiveSystemFn<F>
@@ -46,7 +47,7 @@ where
 
     fn initialize(&mut self, _: &mut World) {}
 
-    fn check_change_tick(&mut self, change_tick: u32) {
+    fn check_change_tick(&mut self, change_tick: Tick) {
         check_system_change_tick(&mut self.last_change_tick, change_tick, self.name.as_ref());
     }
 }
@@ -96,7 +97,7 @@ impl ExclusiveSystem for ExclusiveSystemCoerced {
         self.system.initialize(world);
     }
 
-    fn check_change_tick(&mut self, change_tick: u32) {
+    fn check_change_tick(&mut self, change_tick: Tick) {
         self.system.check_change_tick(change_tick);
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
-    component::ComponentId,
-    query::{Access, FilteredAccessSet},
+    component::{ComponentId, Tick},
+    query::{Access, FilteredAccess, FilteredAccessSet},
     system::{
         check_system_change_tick, ReadOnlySystemParamFetch, System, SystemParam, SystemParamFetch,
         SystemParamItem, SystemParamState,
@@ -9,7 +9,8 @@ use crate::{
     world::{World, WorldId},
 };
 use bevy_ecs_macros::all_tuples;
-use std::{borrow::Cow, marker::PhantomData};
+use std::{borrow::Cow, fmt, marker::PhantomData};
+use thiserror::Error;
 
 /// The metadata of a [`System`].
 pub struct SystemMeta {
@@ -19,7 +20,13 @@ pub struct SystemMeta {
     // NOTE: this must be kept private. making a SystemMeta non-send is irreversible to prevent
     // SystemParams from overriding each other
     is_send: bool,
-    pub(crate) last_change_tick: u32,
+    pub(crate) last_change_tick: Tick,
+    /// The parameter owning each access in `component_access_set`, in insertion order.
+    access_params: Vec<SystemParamInfo>,
+    access_conflicts: Vec<SystemParamConflict>,
+    param_depth: usize,
+    param_count: usize,
+    current_param: usize,
 }
 
 impl SystemMeta {
@@ -30,9 +37,20 @@ impl SystemMeta {
             component_access_set: FilteredAccessSet::default(),
             is_send: true,
             last_change_tick: 0,
+            access_params: Vec::new(),
+            access_conflicts: Vec::new(),
+            param_depth: 0,
+            param_count: 0,
+            current_param: 0,
         }
     }
 
+    /// Returns the system's name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
     /// Returns true if the system is [`Send`].
     #[inline]
     pub fn is_send(&self) -> bool {
@@ -48,9 +66,231 @@ impl SystemMeta {
     }
 
     #[inline]
-    pub(crate) fn check_change_tick(&mut self, change_tick: u32) {
+    pub(crate) fn check_change_tick(&mut self, change_tick: Tick) {
         check_system_change_tick(&mut self.last_change_tick, change_tick, self.name.as_ref());
     }
+
+    /// Marks the start of a parameter list. Returns true if this is the system's outermost list,
+    /// whose elements are the parameters reported in [`SystemParamConflict`]s.
+    #[inline]
+    pub(crate) fn enter_param_list(&mut self) -> bool {
+        self.param_depth += 1;
+        self.param_depth == 1
+    }
+
+    #[inline]
+    pub(crate) fn exit_param_list(&mut self) {
+        self.param_depth -= 1;
+    }
+
+    /// Advances to the next parameter of the outermost parameter list.
+    #[inline]
+    pub(crate) fn next_param(&mut self) {
+        self.current_param = self.param_count;
+        self.param_count += 1;
+    }
+
+    /// Records every conflict between `access` and the component access registered by previously
+    /// initialized parameters. Conflicts are reported once all parameters are initialized.
+    pub(crate) fn check_component_access(
+        &mut self,
+        param_name: &str,
+        access: &FilteredAccess<ComponentId>,
+        world: &World,
+    ) {
+        self.record_access_conflicts(AccessConflictKind::Component, param_name, access, world);
+    }
+
+    fn record_access_conflicts(
+        &mut self,
+        kind: AccessConflictKind,
+        param_name: &str,
+        access: &FilteredAccess<ComponentId>,
+        world: &World,
+    ) {
+        let current = SystemParamInfo {
+            index: self.current_param,
+            name: param_name.to_string().into(),
+        };
+        for (access_index, conflicts) in self.component_access_set.get_conflicts_per_access(access)
+        {
+            self.access_conflicts.push(SystemParamConflict {
+                kind,
+                first: self.access_params[access_index].clone(),
+                second: current.clone(),
+                accesses: conflicts
+                    .into_iter()
+                    .map(|id| world.components().get_info(id).unwrap().name().to_string())
+                    .collect(),
+            });
+        }
+    }
+
+    /// Registers `access` as used by the parameter currently being initialized.
+    pub(crate) fn add_component_access(
+        &mut self,
+        param_name: &str,
+        access: FilteredAccess<ComponentId>,
+    ) {
+        self.access_params.push(SystemParamInfo {
+            index: self.current_param,
+            name: param_name.to_string().into(),
+        });
+        self.component_access_set.add(access);
+    }
+
+    /// Checks `access` against previously initialized parameters, then registers it.
+    pub(crate) fn add_checked_component_access(
+        &mut self,
+        param_name: &str,
+        access: FilteredAccess<ComponentId>,
+        world: &World,
+    ) {
+        self.check_component_access(param_name, &access, world);
+        self.add_component_access(param_name, access);
+    }
+
+    /// Like [`Self::add_checked_component_access`], for parameters that access resources.
+    pub(crate) fn add_checked_resource_access(
+        &mut self,
+        param_name: &str,
+        access: FilteredAccess<ComponentId>,
+        world: &World,
+    ) {
+        self.record_access_conflicts(AccessConflictKind::Resource, param_name, &access, world);
+        self.add_component_access(param_name, access);
+    }
+
+    /// Discards the accesses registered by the parameters, so that they can be initialized again
+    /// after a failed initialization.
+    pub(crate) fn reset_access(&mut self) {
+        self.component_access_set = FilteredAccessSet::default();
+        self.archetype_component_access = Access::default();
+        self.access_params.clear();
+        self.access_conflicts.clear();
+        self.param_depth = 0;
+        self.param_count = 0;
+        self.current_param = 0;
+    }
+
+    /// Returns an error describing all access conflicts found while initializing parameters.
+    pub(crate) fn take_access_conflicts(&mut self) -> Result<(), SystemAccessConflicts> {
+        if self.access_conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(SystemAccessConflicts {
+                system_name: self.name.clone(),
+                conflicts: std::mem::take(&mut self.access_conflicts),
+            })
+        }
+    }
+}
+
+/// A parameter of a [`System`], identified by its position in the system's parameter list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemParamInfo {
+    /// The zero-based position of the parameter in the system's parameter list.
+    pub index: usize,
+    /// A readable description of the parameter, e.g. `Query<&mut Transform, ()>`.
+    pub name: Cow<'static, str>,
+}
+
+impl fmt::Display for SystemParamInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parameter #{} `{}`", self.index, self.name)
+    }
+}
+
+/// The kind of data accessed by two conflicting parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessConflictKind {
+    /// Both parameters access the same components, e.g. two [`Query`](crate::system::Query)s.
+    Component,
+    /// Both parameters access the same resource, e.g. a [`Res`](crate::system::Res) and a
+    /// [`ResMut`](crate::system::ResMut).
+    Resource,
+}
+
+impl AccessConflictKind {
+    /// Returns the error code identifying this kind of conflict.
+    pub fn code(self) -> &'static str {
+        match self {
+            AccessConflictKind::Component => "B0001",
+            AccessConflictKind::Resource => "B0002",
+        }
+    }
+}
+
+/// Two parameters of the same [`System`] whose data access conflicts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemParamConflict {
+    /// Whether the parameters conflict over components or resources.
+    pub kind: AccessConflictKind,
+    /// The parameter that was initialized first.
+    pub first: SystemParamInfo,
+    /// The parameter whose access conflicts with `first`.
+    pub second: SystemParamInfo,
+    /// Names of the components and resources accessed by both parameters, mutably by at least one.
+    pub accesses: Vec<String>,
+}
+
+/// All access conflicts between the parameters of a single [`System`].
+///
+/// Returned by [`FunctionSystem::try_initialize`] and [`SystemState::try_new`]. Other
+/// initialization paths panic with this error's message.
+#[derive(Debug, Clone, Error)]
+pub struct SystemAccessConflicts {
+    pub system_name: Cow<'static, str>,
+    pub conflicts: Vec<SystemParamConflict>,
+}
+
+impl fmt::Display for SystemAccessConflicts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (kind, data, hint) in [
+            (
+                AccessConflictKind::Component,
+                "component",
+                "Consider using `Without<T>` to create disjoint Queries or merging conflicting Queries into a `QuerySet`.",
+            ),
+            (
+                AccessConflictKind::Resource,
+                "resource",
+                "Consider removing the duplicate access.",
+            ),
+        ] {
+            let mut conflicts = self
+                .conflicts
+                .iter()
+                .filter(|conflict| conflict.kind == kind)
+                .peekable();
+            if conflicts.peek().is_none() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            writeln!(
+                f,
+                "error[{}]: system {} has parameters with conflicting {} access:",
+                kind.code(),
+                self.system_name,
+                data
+            )?;
+            for conflict in conflicts {
+                writeln!(
+                    f,
+                    "  - {} and {} both access {}, at least one of them mutably",
+                    conflict.first,
+                    conflict.second,
+                    conflict.accesses.join(", ")
+                )?;
+            }
+            write!(f, "{}", hint)?;
+        }
+        Ok(())
+    }
 }
 
 // TODO: Actually use this in FunctionSystem. We should probably only do this once Systems are constructed using a World reference
@@ -69,18 +309,33 @@ impl<Param: SystemParam> SystemState<Param> {
         Self::with_config(world, config)
     }
 
+    /// Like [`SystemState::new`], but returns an error instead of panicking if the parameters'
+    /// data access conflicts.
+    pub fn try_new(world: &mut World) -> Result<Self, SystemAccessConflicts> {
+        let config = <Param::Fetch as SystemParamState>::default_config();
+        Self::try_with_config(world, config)
+    }
+
     pub fn with_config(
         world: &mut World,
         config: <Param::Fetch as SystemParamState>::Config,
     ) -> Self {
+        Self::try_with_config(world, config).unwrap_or_else(|conflicts| panic!("{}", conflicts))
+    }
+
+    pub fn try_with_config(
+        world: &mut World,
+        config: <Param::Fetch as SystemParamState>::Config,
+    ) -> Result<Self, SystemAccessConflicts> {
         let mut meta = SystemMeta::new::<Param>();
         let param_state = <Param::Fetch as SystemParamState>::init(world, &mut meta, config);
-        Self {
+        meta.take_access_conflicts()?;
+        Ok(Self {
             meta,
             param_state,
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
-        }
+        })
     }
 
     #[inline]
@@ -231,7 +486,7 @@ impl<P: SystemParam + 'static> System for ParamSystem<P> {
         // already initialized by nature of the SystemState being constructed
     }
 
-    fn check_change_tick(&mut self, change_tick: u32) {
+    fn check_change_tick(&mut self, change_tick: Tick) {
         self.state.meta.check_change_tick(change_tick);
     }
 }
@@ -340,6 +595,37 @@ impl<In, Out, Param: SystemParam, Marker, F> FunctionSystem<In, Out, Param, Mark
         f(self.config.as_mut().unwrap());
         self
     }
+
+    /// Initializes the system's parameters like [`System::initialize`], but returns an error
+    /// listing every pair of conflicting parameters instead of panicking.
+    ///
+    /// The system is left uninitialized if an error is returned, with its config untouched, so
+    /// initialization can be retried. This is why the config must be [`Clone`].
+    pub fn try_initialize(&mut self, world: &mut World) -> Result<(), SystemAccessConflicts>
+    where
+        <Param::Fetch as SystemParamState>::Config: Clone,
+    {
+        let config = self.config.clone().unwrap();
+        self.param_state = Some(self.init_param_state(world, config)?);
+        self.config = None;
+        Ok(())
+    }
+
+    /// Initializes the system's parameters with `config`. On error, the accesses registered by
+    /// the parameters are discarded.
+    fn init_param_state(
+        &mut self,
+        world: &mut World,
+        config: <Param::Fetch as SystemParamState>::Config,
+    ) -> Result<Param::Fetch, SystemAccessConflicts> {
+        let param_state =
+            <Param::Fetch as SystemParamState>::init(world, &mut self.system_meta, config);
+        if let Err(conflicts) = self.system_meta.take_access_conflicts() {
+            self.system_meta.reset_access();
+            return Err(conflicts);
+        }
+        Ok(param_state)
+    }
 }
 
 /// Provides `my_system.config(...)` API.
@@ -458,15 +744,15 @@ where
 
     #[inline]
     fn initialize(&mut self, world: &mut World) {
-        self.param_state = Some(<Param::Fetch as SystemParamState>::init(
-            world,
-            &mut self.system_meta,
-            self.config.take().unwrap(),
-        ));
+        let config = self.config.take().unwrap();
+        match self.init_param_state(world, config) {
+            Ok(param_state) => self.param_state = Some(param_state),
+            Err(conflicts) => panic!("{}", conflicts),
+        }
     }
 
     #[inline]
-    fn check_change_tick(&mut self, change_tick: u32) {
+    fn check_change_tick(&mut self, change_tick: Tick) {
         check_system_change_tick(
             &mut self.system_meta.last_change_tick,
             change_tick,
@@ -487,7 +773,7 @@ pub trait SystemParamFunction<In, Out, Param: SystemParam, Marker>: Send + Sync
         state: &mut Param::Fetch,
         system_meta: &SystemMeta,
         world: &World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Out;
 }
 
@@ -501,7 +787,7 @@ macro_rules! impl_system_function {
                 FnMut($(<<$param as SystemParam>::Fetch as SystemParamFetch>::Item),*) -> Out, Out: 'static
         {
             #[inline]
-            unsafe fn run(&mut self, _input: (), state: &mut <($($param,)*) as SystemParam>::Fetch, system_meta: &SystemMeta, world: &World, change_tick: u32) -> Out {
+            unsafe fn run(&mut self, _input: (), state: &mut <($($param,)*) as SystemParam>::Fetch, system_meta: &SystemMeta, world: &World, change_tick: Tick) -> Out {
                 // Yes, this is strange, but rustc fails to compile this impl
                 // without using this function.
                 #[allow(clippy::too_many_arguments)]
@@ -524,7 +810,7 @@ macro_rules! impl_system_function {
                 FnMut(In<Input>, $(<<$param as SystemParam>::Fetch as SystemParamFetch>::Item),*) -> Out, Out: 'static
         {
             #[inline]
-            unsafe fn run(&mut self, input: Input, state: &mut <($($param,)*) as SystemParam>::Fetch, system_meta: &SystemMeta, world: &World, change_tick: u32) -> Out {
+            unsafe fn run(&mut self, input: Input, state: &mut <($($param,)*) as SystemParam>::Fetch, system_meta: &SystemMeta, world: &World, change_tick: Tick) -> Out {
                 #[allow(clippy::too_many_arguments)]
                 fn call_inner<Input, Out, $($param,)*>(
                     mut f: impl FnMut(In<Input>, $($param,)*)->Out,
@@ -1,13 +1,14 @@
 use crate::{
-    component::Component,
-    entity::Entity,
+    component::{Component, Tick},
+    entity::{Entity, EntityReference},
     query::{
-        Fetch, FilterFetch, QueryCombinationIter, QueryEntityError, QueryIter, QueryState,
-        WorldQuery,
+        verify_entities_unique, ChunkFetch, Fetch, FilterFetch, QueryChunkIter,
+        QueryCombinationIter, QueryEntityError, QueryIter, QueryJoinIter, QueryManyIter,
+        QuerySortedIter, QueryState, QueryTransmuteError, WorldQuery,
     },
     world::{Mut, World},
 };
-use std::{any::TypeId, fmt::Debug};
+use std::{any::TypeId, cmp::Ordering, fmt::Debug};
 use thiserror::Error;
 
 /// Provides scoped access to components in a [`World`].
@@ -245,8 +246,8 @@ where
 {
     pub(crate) world: &'world World,
     pub(crate) state: &'state QueryState<Q, F>,
-    pub(crate) last_change_tick: u32,
-    pub(crate) change_tick: u32,
+    pub(crate) last_change_tick: Tick,
+    pub(crate) change_tick: Tick,
 }
 
 impl<'w, 's, Q: WorldQuery, F: WorldQuery> Query<'w, 's, Q, F>
@@ -263,8 +264,8 @@ where
     pub(crate) unsafe fn new(
         world: &'w World,
         state: &'s QueryState<Q, F>,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Self {
         Self {
             world,
@@ -337,6 +338,509 @@ where
         }
     }
 
+    /// Returns an [`Iterator`] over the read-only query results of the given list of
+    /// [`Entity`]s.
+    ///
+    /// Entities that don't exist or don't match the query are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Children(Vec<Entity>);
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn total_child_health_system(parents: Query<&Children>, health: Query<&Health>) {
+    ///     for children in parents.iter() {
+    ///         let total: u32 = health.iter_many(children.0.iter().copied()).map(|h| h.0).sum();
+    ///         println!("{}", total);
+    ///     }
+    /// }
+    /// # total_child_health_system.system();
+    /// ```
+    #[inline]
+    pub fn iter_many<EntityList: IntoIterator<Item = Entity>>(
+        &'s self,
+        entities: EntityList,
+    ) -> QueryManyIter<'w, 's, Q, Q::ReadOnlyFetch, F, EntityList::IntoIter> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state.iter_many_unchecked_manual(
+                self.world,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by the keys `f` returns for them.
+    ///
+    /// Unlike [`Self::iter`], the order does not depend on how entities are laid out in
+    /// tables, so it stays the same when entities move between archetypes. Results with equal
+    /// keys are ordered by [`Entity::id`]. The keys are computed once per result and sorted
+    /// each time this is called.
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::iter_sorted_by_key_mut`] for queries that contain at least one mutable component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Name(String);
+    /// # #[derive(Component)]
+    /// # struct Depth(u32);
+    /// fn draw_system(query: Query<(&Name, &Depth)>) {
+    ///     for (name, _) in query.iter_sorted_by_key(|(_, depth)| depth.0) {
+    ///         println!("drawing {}", name.0);
+    ///     }
+    /// }
+    /// # draw_system.system();
+    /// ```
+    #[inline]
+    pub fn iter_sorted_by_key<K: Ord>(
+        &'s self,
+        f: impl FnMut(&<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item) -> K,
+    ) -> QuerySortedIter<'w, 's, Q, Q::ReadOnlyFetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            let entities = self.state.sorted_entities_by_key_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+                f,
+            );
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by the keys `f` returns for their
+    /// read-only form. See [`Self::iter_sorted_by_key`].
+    #[inline]
+    pub fn iter_sorted_by_key_mut<K: Ord>(
+        &mut self,
+        f: impl FnMut(&<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item) -> K,
+    ) -> QuerySortedIter<'_, '_, Q, Q::Fetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict. The read-only
+        // results passed to `f` cannot outlive it.
+        unsafe {
+            let entities = self.state.sorted_entities_by_key_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+                f,
+            );
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by `compare`.
+    ///
+    /// Results that compare equal are ordered by [`Entity::id`]. See
+    /// [`Self::iter_sorted_by_key`] for more details.
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::iter_sorted_by_mut`] for queries that contain at least one mutable component.
+    #[inline]
+    pub fn iter_sorted_by(
+        &'s self,
+        compare: impl FnMut(
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+        ) -> Ordering,
+    ) -> QuerySortedIter<'w, 's, Q, Q::ReadOnlyFetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            let entities = self.state.sorted_entities_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+                compare,
+            );
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by `compare` applied to their
+    /// read-only form. See [`Self::iter_sorted_by`].
+    #[inline]
+    pub fn iter_sorted_by_mut(
+        &mut self,
+        compare: impl FnMut(
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+            &<Q::ReadOnlyFetch as Fetch<'_, '_>>::Item,
+        ) -> Ordering,
+    ) -> QuerySortedIter<'_, '_, Q, Q::Fetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict. The read-only
+        // results passed to `compare` cannot outlive it.
+        unsafe {
+            let entities = self.state.sorted_entities_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+                compare,
+            );
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by [`Entity::id`].
+    ///
+    /// This is cheaper than [`Self::iter_sorted_by_key`], since it only sorts the matched
+    /// entities and does not fetch the query results twice.
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::iter_by_entity_mut`] for queries that contain at least one mutable component.
+    #[inline]
+    pub fn iter_by_entity(&'s self) -> QuerySortedIter<'w, 's, Q, Q::ReadOnlyFetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            let mut entities = self.state.matched_entities_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+            );
+            entities.sort_unstable_by_key(|entity| entity.id());
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the query results, ordered by [`Entity::id`].
+    #[inline]
+    pub fn iter_by_entity_mut(&mut self) -> QuerySortedIter<'_, '_, Q, Q::Fetch, F> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            let mut entities = self.state.matched_entities_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+            );
+            entities.sort_unstable_by_key(|entity| entity.id());
+            QuerySortedIter::new(
+                self.world,
+                self.state,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over pairs of query results of this query and `other`, joined
+    /// through the `R` component of this query's entities.
+    ///
+    /// For every result of this query, the entity referenced by its `R` component is looked up
+    /// in `other`. Results whose referenced entity does not exist or does not match `other` are
+    /// skipped.
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::join_on_mut`] and [`Self::for_each_join_mut`] for mutable access.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::entity::EntityReference;
+    ///
+    /// #[derive(Component)]
+    /// struct Target(Entity);
+    ///
+    /// impl EntityReference for Target {
+    ///     fn entity(&self) -> Entity {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// # #[derive(Component)]
+    /// # struct Name(String);
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn report_targets_system(attackers: Query<(&Name, &Target)>, targets: Query<&Health>) {
+    ///     for ((name, _), health) in attackers.join_on::<Target, _, _>(&targets) {
+    ///         println!("{} targets an entity with {} health", name.0, health.0);
+    ///     }
+    /// }
+    /// # report_targets_system.system();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this query does not read `R`, or if `other` belongs to a different [`World`].
+    #[inline]
+    #[track_caller]
+    pub fn join_on<'a, R: EntityReference, JQ: WorldQuery, JF: WorldQuery>(
+        &'a self,
+        other: &'a Query<'_, '_, JQ, JF>,
+    ) -> QueryJoinIter<'a, 'a, R, Q, Q::ReadOnlyFetch, F, JQ, JQ::ReadOnlyFetch, JF>
+    where
+        JF::Fetch: FilterFetch,
+    {
+        self.validate_join::<R, JQ, JF>(other);
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            QueryJoinIter::new(
+                self.world,
+                self.state,
+                other.state,
+                self.last_change_tick,
+                other.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over pairs of query results of this query and `other`, joined
+    /// through the `R` component of this query's entities.
+    ///
+    /// The results of `other` are immutable, as several entities can reference the same entity.
+    /// Use [`Self::for_each_join_mut`] to mutate them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this query does not read `R`, or if `other` belongs to a different [`World`].
+    #[inline]
+    #[track_caller]
+    pub fn join_on_mut<'a, R: EntityReference, JQ: WorldQuery, JF: WorldQuery>(
+        &'a mut self,
+        other: &'a Query<'_, '_, JQ, JF>,
+    ) -> QueryJoinIter<'a, 'a, R, Q, Q::Fetch, F, JQ, JQ::ReadOnlyFetch, JF>
+    where
+        JF::Fetch: FilterFetch,
+    {
+        self.validate_join::<R, JQ, JF>(other);
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            QueryJoinIter::new(
+                self.world,
+                self.state,
+                other.state,
+                self.last_change_tick,
+                other.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Runs `f` on each pair of mutable query results of this query and `other`, joined
+    /// through the `R` component of this query's entities.
+    ///
+    /// Unlike [`Self::join_on_mut`], this gives mutable access to the results of `other`. They
+    /// cannot be kept across calls to `f`, as several entities can reference the same entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity::EntityReference;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Target(Entity);
+    /// #
+    /// # impl EntityReference for Target {
+    /// #     fn entity(&self) -> Entity {
+    /// #         self.0
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Damage(u32);
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn attack_system(mut attackers: Query<(&Damage, &Target)>, mut targets: Query<&mut Health>) {
+    ///     attackers.for_each_join_mut::<Target, _, _>(&mut targets, |(damage, _), mut health| {
+    ///         health.0 = health.0.saturating_sub(damage.0);
+    ///     });
+    /// }
+    /// # attack_system.system();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if this query does not read `R`, or if `other` belongs to a different [`World`].
+    #[inline]
+    #[track_caller]
+    pub fn for_each_join_mut<R: EntityReference, JQ: WorldQuery, JF: WorldQuery>(
+        &mut self,
+        other: &mut Query<'_, '_, JQ, JF>,
+        mut f: impl FnMut(<Q::Fetch as Fetch<'_, '_>>::Item, <JQ::Fetch as Fetch<'_, '_>>::Item),
+    ) where
+        JF::Fetch: FilterFetch,
+    {
+        self.validate_join::<R, JQ, JF>(other);
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict.
+        // results of `other` cannot escape `f`, so they never alias
+        unsafe {
+            let iter = QueryJoinIter::<R, Q, Q::Fetch, F, JQ, JQ::Fetch, JF>::new(
+                self.world,
+                self.state,
+                other.state,
+                self.last_change_tick,
+                other.last_change_tick,
+                self.change_tick,
+            );
+            for (item, joined) in iter {
+                f(item, joined);
+            }
+        }
+    }
+
+    /// Panics if this query cannot be joined with `other` through `R`.
+    #[track_caller]
+    fn validate_join<R: EntityReference, JQ: WorldQuery, JF: WorldQuery>(
+        &self,
+        other: &Query<'_, '_, JQ, JF>,
+    ) where
+        JF::Fetch: FilterFetch,
+    {
+        other.state.validate_world(self.world);
+        let reads_reference =
+            self.world
+                .components()
+                .component_id::<R>()
+                .is_some_and(|component_id| {
+                    self.state.component_access.access().has_read(component_id)
+                });
+        if !reads_reference {
+            panic!(
+                "Cannot join {} on {}, as the query does not read it.",
+                std::any::type_name::<Self>(),
+                std::any::type_name::<R>()
+            );
+        }
+    }
+
+    /// Returns an [`Iterator`] over chunks of the query results, where each chunk holds the
+    /// results of a run of consecutive rows of a table.
+    ///
+    /// Chunks let components be processed in bulk as slices, e.g. `(&[Entity], &[A])` for a
+    /// `Query<(Entity, &A)>`. Sparse set components are gathered into a `Vec` per chunk instead,
+    /// see [`ChunkStorage`](crate::query::ChunkStorage). If the query accesses sparse set
+    /// components, the rows of each archetype that pass the filter are split into separate
+    /// chunks.
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::iter_chunks_mut`] for queries that contain at least one mutable component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(f32);
+    /// fn total_health_system(query: Query<&Health>) {
+    ///     let mut total = 0.0;
+    ///     for health in query.iter_chunks() {
+    ///         total += health.iter().map(|health| health.0).sum::<f32>();
+    ///     }
+    ///     println!("total health: {}", total);
+    /// }
+    /// # total_health_system.system();
+    /// ```
+    #[inline]
+    pub fn iter_chunks(&'s self) -> QueryChunkIter<'w, 's, Q, Q::ReadOnlyFetch, F>
+    where
+        Q::ReadOnlyFetch: ChunkFetch<'w, 's>,
+    {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state.iter_chunks_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over chunks of the query results.
+    ///
+    /// Mutable components are returned as [`ChunkMut`](crate::query::ChunkMut)s, which mark
+    /// every component of the chunk as changed when mutably dereferenced. See
+    /// [`Self::iter_chunks`] for how results are grouped into chunks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Position(f32);
+    /// # #[derive(Component)]
+    /// # struct Velocity(f32);
+    /// fn movement_system(mut query: Query<(&mut Position, &Velocity)>) {
+    ///     for (mut positions, velocities) in query.iter_chunks_mut() {
+    ///         for (position, velocity) in positions.iter_mut().zip(velocities) {
+    ///             position.0 += velocity.0;
+    ///         }
+    ///     }
+    /// }
+    /// # movement_system.system();
+    /// ```
+    #[inline]
+    pub fn iter_chunks_mut<'a>(&'a mut self) -> QueryChunkIter<'a, 'a, Q, Q::Fetch, F>
+    where
+        Q::Fetch: ChunkFetch<'a, 'a>,
+    {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state.iter_chunks_unchecked_manual(
+                self.world,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
     /// Returns an [`Iterator`] over all possible combinations of `K` query results without repetition.
     /// This can only return immutable data
     ///
@@ -502,6 +1006,101 @@ where
         };
     }
 
+    /// Runs `f` on each query result in parallel, using the world's
+    /// [`TaskPool`](crate::task_pool::TaskPool).
+    ///
+    /// The query results are split into batches of up to `batch_size` entities, and each batch
+    /// is run as a separate job. Batches never span more than one table (or archetype, if the
+    /// query accesses sparse set or tag components).
+    ///
+    /// This can only return immutable data (mutable data will be cast to an immutable form).
+    /// See [`Self::par_for_each_mut`] for queries that contain at least one mutable component.
+    ///
+    /// # Panics
+    ///
+    /// If `batch_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Enemy { health: f32 }
+    /// fn count_dying_enemies_system(query: Query<&Enemy>) {
+    ///     let dying = AtomicUsize::new(0);
+    ///     query.par_for_each(64, |enemy| {
+    ///         if enemy.health < 10.0 {
+    ///             dying.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     });
+    ///     println!("{} enemies are dying", dying.into_inner());
+    /// }
+    /// # count_dying_enemies_system.system();
+    /// ```
+    #[inline]
+    pub fn par_for_each<FN: Fn(<Q::ReadOnlyFetch as Fetch<'w, 's>>::Item) + Send + Sync>(
+        &'s self,
+        batch_size: usize,
+        f: FN,
+    ) {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state
+                .par_for_each_unchecked_manual::<Q::ReadOnlyFetch, FN>(
+                    self.world,
+                    batch_size,
+                    f,
+                    self.last_change_tick,
+                    self.change_tick,
+                )
+        };
+    }
+
+    /// Runs `f` on each query result in parallel, using the world's
+    /// [`TaskPool`](crate::task_pool::TaskPool). See [`Self::par_for_each`] for how results
+    /// are batched.
+    ///
+    /// # Panics
+    ///
+    /// If `batch_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Velocity { x: f32, y: f32, z: f32 }
+    /// fn gravity_system(mut query: Query<&mut Velocity>) {
+    ///     const DELTA: f32 = 1.0 / 60.0;
+    ///     query.par_for_each_mut(256, |mut velocity| {
+    ///         velocity.y -= 9.8 * DELTA;
+    ///     });
+    /// }
+    /// # gravity_system.system();
+    /// ```
+    #[inline]
+    pub fn par_for_each_mut<'a, FN: Fn(<Q::Fetch as Fetch<'a, 'a>>::Item) + Send + Sync>(
+        &'a mut self,
+        batch_size: usize,
+        f: FN,
+    ) {
+        // SAFE: system runs without conflicts with other systems. same-system queries have runtime
+        // borrow checks when they conflict
+        unsafe {
+            self.state.par_for_each_unchecked_manual::<Q::Fetch, FN>(
+                self.world,
+                batch_size,
+                f,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        };
+    }
+
     /// Returns the query result for the given [`Entity`].
     ///
     /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is
@@ -591,6 +1190,71 @@ where
         }
     }
 
+    /// Returns the query results for the given array of [`Entity`]s.
+    ///
+    /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is
+    /// returned instead.
+    ///
+    /// This can only return immutable data. See [`get_many_mut`](Self::get_many_mut) for
+    /// queries that contain at least one mutable component.
+    #[inline]
+    pub fn get_many<const N: usize>(
+        &'s self,
+        entities: [Entity; N],
+    ) -> Result<[<Q::ReadOnlyFetch as Fetch<'w, 's>>::Item; N], QueryEntityError> {
+        // SAFE: system runs without conflicts with other systems.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state.get_many_unchecked_manual::<Q::ReadOnlyFetch, N>(
+                self.world,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
+    /// Returns the query results for the given array of distinct [`Entity`]s.
+    ///
+    /// If the same entity is requested more than once,
+    /// [`QueryEntityError::AliasedMutability`] is returned. In case of a nonexisting entity or
+    /// mismatched component, another [`QueryEntityError`] is returned instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # struct Attack { attacker: Entity, target: Entity }
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn life_steal_system(mut query: Query<&mut Health>, attack: Res<Attack>) {
+    ///     if let Ok([mut attacker, mut target]) = query.get_many_mut([attack.attacker, attack.target]) {
+    ///         target.0 -= 1;
+    ///         attacker.0 += 1;
+    ///     }
+    /// }
+    /// # life_steal_system.system();
+    /// ```
+    #[inline]
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        entities: [Entity; N],
+    ) -> Result<[<Q::Fetch as Fetch<'_, '_>>::Item; N], QueryEntityError> {
+        verify_entities_unique(&entities)?;
+        // SAFE: system runs without conflicts with other systems, and the entities are distinct.
+        // same-system queries have runtime borrow checks when they conflict
+        unsafe {
+            self.state.get_many_unchecked_manual::<Q::Fetch, N>(
+                self.world,
+                entities,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
+
     /// Returns the query result for the given [`Entity`].
     ///
     /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is
@@ -912,6 +1576,95 @@ where
         self.state
             .is_empty(self.world, self.last_change_tick, self.change_tick)
     }
+
+    /// Returns a [`QueryLens`] that can be used to get a query with a narrower [`WorldQuery`].
+    ///
+    /// The new query keeps this query's filter `F`, including filters that are checked per entity
+    /// like [`Changed`](crate::query::Changed), and only returns results for entities in the
+    /// archetypes this query matches. This makes it possible to pass a query to a function that
+    /// expects a query over fewer components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// # #[derive(Component)]
+    /// # struct Name(String);
+    /// #
+    /// fn total_health(query: Query<&Health, With<Player>>) -> u32 {
+    ///     query.iter().map(|health| health.0).sum()
+    /// }
+    ///
+    /// fn report_player_health_system(mut query: Query<(&Name, &Health), With<Player>>) {
+    ///     let mut lens = query.transmute_lens::<&Health>();
+    ///     println!("players have {} health in total", total_health(lens.query()));
+    /// }
+    /// # report_player_health_system.system();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `NewQ` accesses components in a way this query does not, e.g. when requesting
+    /// `&mut T` from a query that only reads `T`, or if `F` does not support
+    /// [`FetchState::try_init`](crate::query::FetchState::try_init). Use
+    /// [`try_transmute_lens`](Self::try_transmute_lens) to return a `Result` instead of panicking.
+    #[track_caller]
+    pub fn transmute_lens<NewQ: WorldQuery>(&mut self) -> QueryLens<'_, NewQ, F> {
+        self.try_transmute_lens().unwrap()
+    }
+
+    /// Returns a [`QueryLens`] that can be used to get a query with a narrower [`WorldQuery`].
+    ///
+    /// If `NewQ` accesses components in a way this query does not, a [`QueryTransmuteError`] is
+    /// returned instead. See [`transmute_lens`](Self::transmute_lens) for more details.
+    pub fn try_transmute_lens<NewQ: WorldQuery>(
+        &mut self,
+    ) -> Result<QueryLens<'_, NewQ, F>, QueryTransmuteError> {
+        Ok(QueryLens {
+            world: self.world,
+            state: self.state.try_transmute(self.world)?,
+            last_change_tick: self.last_change_tick,
+            change_tick: self.change_tick,
+        })
+    }
+}
+
+/// Holds the state of a [`Query`] transmuted into a narrower [`WorldQuery`].
+///
+/// This struct is created by the [`Query::transmute_lens`] and [`Query::try_transmute_lens`]
+/// methods. Use [`QueryLens::query`] to get the transmuted [`Query`].
+pub struct QueryLens<'w, Q: WorldQuery, F: WorldQuery = ()>
+where
+    F::Fetch: FilterFetch,
+{
+    world: &'w World,
+    state: QueryState<Q, F>,
+    last_change_tick: Tick,
+    change_tick: Tick,
+}
+
+impl<'w, Q: WorldQuery, F: WorldQuery> QueryLens<'w, Q, F>
+where
+    F::Fetch: FilterFetch,
+{
+    /// Returns the transmuted [`Query`].
+    pub fn query(&mut self) -> Query<'_, '_, Q, F> {
+        // SAFE: the lens borrows the original query mutably, and its access is a subset of the
+        // original query's access
+        unsafe {
+            Query::new(
+                self.world,
+                &self.state,
+                self.last_change_tick,
+                self.change_tick,
+            )
+        }
+    }
 }
 
 /// An error that occurs when retrieving a specific [`Entity`]'s component from a [`Query`]
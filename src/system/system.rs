@@ -2,7 +2,7 @@ use bevy_utils::tracing::warn;
 
 use crate::{
     archetype::{Archetype, ArchetypeComponentId},
-    component::ComponentId,
+    component::{ComponentId, Tick},
     query::Access,
     world::World,
 };
@@ -55,19 +55,23 @@ pub trait System: Send + Sync + 'static {
     fn apply_buffers(&mut self, world: &mut World);
     /// Initialize the system.
     fn initialize(&mut self, _world: &mut World);
-    fn check_change_tick(&mut self, change_tick: u32);
+    fn check_change_tick(&mut self, change_tick: Tick);
 }
 
 /// A convenience type alias for a boxed [`System`] trait object.
 pub type BoxedSystem<In = (), Out = ()> = Box<dyn System<In = In, Out = Out>>;
 
 pub(crate) fn check_system_change_tick(
-    last_change_tick: &mut u32,
-    change_tick: u32,
+    last_change_tick: &mut Tick,
+    change_tick: Tick,
     system_name: &str,
 ) {
+    // 64-bit ticks can't realistically wrap around, so they never need to be clamped
+    if cfg!(feature = "u64_ticks") {
+        return;
+    }
     let tick_delta = change_tick.wrapping_sub(*last_change_tick);
-    const MAX_DELTA: u32 = (u32::MAX / 4) * 3;
+    const MAX_DELTA: Tick = (Tick::MAX / 4) * 3;
     // Clamp to max delta
     if tick_delta > MAX_DELTA {
         warn!(
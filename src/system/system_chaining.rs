@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId},
-    component::ComponentId,
+    component::{ComponentId, Tick},
     query::Access,
     system::{IntoSystem, System},
     world::World,
@@ -101,7 +101,7 @@ impl<SystemA: System, SystemB: System<In = SystemA::Out>> System for ChainSystem
             .extend(self.system_b.component_access());
     }
 
-    fn check_change_tick(&mut self, change_tick: u32) {
+    fn check_change_tick(&mut self, change_tick: Tick) {
         self.system_a.check_change_tick(change_tick);
         self.system_b.check_change_tick(change_tick);
     }
@@ -3,11 +3,9 @@ use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
     change_detection::Ticks,
-    component::{Component, ComponentId, ComponentTicks, Components},
+    component::{Component, ComponentId, ComponentTicks, Components, Tick},
     entity::{Entities, Entity},
-    query::{
-        FilterFetch, FilteredAccess, FilteredAccessSet, QueryState, ReadOnlyFetch, WorldQuery,
-    },
+    query::{FilterFetch, FilteredAccess, QueryState, ReadOnlyFetch, WorldQuery},
     system::{CommandQueue, Commands, Query, SystemMeta},
     world::{FromWorld, World},
 };
@@ -96,7 +94,7 @@ pub trait SystemParamFetch<'world, 'state>: SystemParamState {
         state: &'state mut Self,
         system_meta: &SystemMeta,
         world: &'world World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item;
 }
 
@@ -125,17 +123,11 @@ where
 
     fn init(world: &mut World, system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
         let state = QueryState::new(world);
-        assert_component_access_compatibility(
-            &system_meta.name,
-            std::any::type_name::<Q>(),
-            std::any::type_name::<F>(),
-            &system_meta.component_access_set,
-            &state.component_access,
+        system_meta.add_checked_component_access(
+            &query_param_name::<Q, F>(),
+            state.component_access.clone(),
             world,
         );
-        system_meta
-            .component_access_set
-            .add(state.component_access.clone());
         system_meta
             .archetype_component_access
             .extend(&state.archetype_component_access);
@@ -164,38 +156,26 @@ where
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
+        state.remove_pruned_archetypes(world.archetypes());
         Query::new(world, state, system_meta.last_change_tick, change_tick)
     }
 }
 
-fn assert_component_access_compatibility(
-    system_name: &str,
-    query_type: &'static str,
-    filter_type: &'static str,
-    system_access: &FilteredAccessSet<ComponentId>,
-    current: &FilteredAccess<ComponentId>,
-    world: &World,
-) {
-    let mut conflicts = system_access.get_conflicts(current);
-    if conflicts.is_empty() {
-        return;
-    }
-    let conflicting_components = conflicts
-        .drain(..)
-        .map(|component_id| world.components.get_info(component_id).unwrap().name())
-        .collect::<Vec<&str>>();
-    let accesses = conflicting_components.join(", ");
-    panic!("error[B0001]: Query<{}, {}> in system {} accesses component(s) {} in a way that conflicts with a previous system parameter. Consider using `Without<T>` to create disjoint Queries or merging conflicting Queries into a `QuerySet`.",
-                query_type, filter_type, system_name, accesses);
+fn query_param_name<Q, F>() -> String {
+    format!(
+        "Query<{}, {}>",
+        std::any::type_name::<Q>(),
+        std::any::type_name::<F>()
+    )
 }
 
 pub struct QuerySet<'w, 's, T> {
     query_states: &'s T,
     world: &'w World,
-    last_change_tick: u32,
-    change_tick: u32,
+    last_change_tick: Tick,
+    change_tick: Tick,
 }
 
 pub struct QuerySetState<T>(T);
@@ -219,8 +199,8 @@ impl<T> Resource for T where T: Send + Sync + 'static {}
 pub struct Res<'w, T: Resource> {
     value: &'w T,
     ticks: &'w ComponentTicks,
-    last_change_tick: u32,
-    change_tick: u32,
+    last_change_tick: Tick,
+    change_tick: Tick,
 }
 
 // SAFE: Res only reads a single World resource
@@ -286,13 +266,13 @@ unsafe impl<T: Resource> SystemParamState for ResState<T> {
 
     fn init(world: &mut World, system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
         let component_id = world.initialize_resource::<T>();
-        let combined_access = system_meta.component_access_set.combined_access_mut();
-        if combined_access.has_write(component_id) {
-            panic!(
-                "error[B0002]: Res<{}> in system {} conflicts with a previous ResMut<{0}> access. Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        }
-        combined_access.add_read(component_id);
+        let mut access = FilteredAccess::default();
+        access.add_read(component_id);
+        system_meta.add_checked_resource_access(
+            &format!("Res<{}>", std::any::type_name::<T>()),
+            access,
+            world,
+        );
 
         let resource_archetype = world.archetypes.resource();
         let archetype_component_id = resource_archetype
@@ -318,7 +298,7 @@ impl<'w, 's, T: Resource> SystemParamFetch<'w, 's> for ResState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         let column = world
             .get_populated_resource_column(state.component_id)
@@ -367,7 +347,7 @@ impl<'w, 's, T: Resource> SystemParamFetch<'w, 's> for OptionResState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world
             .get_populated_resource_column(state.0.component_id)
@@ -397,17 +377,13 @@ unsafe impl<T: Resource> SystemParamState for ResMutState<T> {
 
     fn init(world: &mut World, system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
         let component_id = world.initialize_resource::<T>();
-        let combined_access = system_meta.component_access_set.combined_access_mut();
-        if combined_access.has_write(component_id) {
-            panic!(
-                "error[B0002]: ResMut<{}> in system {} conflicts with a previous ResMut<{0}> access. Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        } else if combined_access.has_read(component_id) {
-            panic!(
-                "error[B0002]: ResMut<{}> in system {} conflicts with a previous Res<{0}> access. Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        }
-        combined_access.add_write(component_id);
+        let mut access = FilteredAccess::default();
+        access.add_write(component_id);
+        system_meta.add_checked_resource_access(
+            &format!("ResMut<{}>", std::any::type_name::<T>()),
+            access,
+            world,
+        );
 
         let resource_archetype = world.archetypes.resource();
         let archetype_component_id = resource_archetype
@@ -433,7 +409,7 @@ impl<'w, 's, T: Resource> SystemParamFetch<'w, 's> for ResMutState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         let value = world
             .get_resource_unchecked_mut_with_id(state.component_id)
@@ -481,7 +457,7 @@ impl<'w, 's, T: Resource> SystemParamFetch<'w, 's> for OptionResMutState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world
             .get_resource_unchecked_mut_with_id(state.0.component_id)
@@ -526,7 +502,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for CommandQueue {
         state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         Commands::new(state, world)
     }
@@ -616,18 +592,126 @@ impl<'w, 's, T: Resource + FromWorld> SystemParamFetch<'w, 's> for LocalState<T>
         state: &'s mut Self,
         _system_meta: &SystemMeta,
         _world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         Local(&mut state.0)
     }
 }
 
+/// A buffer of deferred [`World`] mutations, used with the [`Deferred`] system parameter.
+///
+/// Each system gets its own buffer. [`SystemBuffer::apply`] is called at the same sync point as
+/// [`Commands`] (when the system's buffers are applied), after which the buffer should be empty.
+pub trait SystemBuffer: FromWorld + Send + Sync + 'static {
+    /// Applies the buffered data to the `world`.
+    fn apply(&mut self, world: &mut World);
+}
+
+impl SystemBuffer for CommandQueue {
+    fn apply(&mut self, world: &mut World) {
+        CommandQueue::apply(self, world);
+    }
+}
+
+/// A system local [`SystemBuffer`] that is applied to the [`World`] at the next sync point.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::system::{Deferred, SystemBuffer};
+///
+/// struct Score(u32);
+///
+/// #[derive(Default)]
+/// struct ScoreBuffer(u32);
+///
+/// impl SystemBuffer for ScoreBuffer {
+///     fn apply(&mut self, world: &mut World) {
+///         world.get_resource_mut::<Score>().unwrap().0 += std::mem::take(&mut self.0);
+///     }
+/// }
+///
+/// fn award_points(mut buffer: Deferred<ScoreBuffer>) {
+///     buffer.0 += 10;
+/// }
+/// # award_points.system();
+/// ```
+pub struct Deferred<'a, T: SystemBuffer>(&'a mut T);
+
+impl<'a, T: SystemBuffer> Debug for Deferred<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Deferred").field(&self.0).finish()
+    }
+}
+
+impl<'a, T: SystemBuffer> Deref for Deferred<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a, T: SystemBuffer> DerefMut for Deferred<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+/// The [`SystemParamState`] of [`Deferred<T>`].
+pub struct DeferredState<T: SystemBuffer>(T);
+
+impl<'a, T: SystemBuffer> SystemParam for Deferred<'a, T> {
+    type Fetch = DeferredState<T>;
+}
+
+// SAFE: Deferred only accesses internal state
+unsafe impl<T: SystemBuffer> ReadOnlySystemParamFetch for DeferredState<T> {}
+
+// SAFE: only local state is accessed
+unsafe impl<T: SystemBuffer> SystemParamState for DeferredState<T> {
+    type Config = Option<T>;
+
+    fn init(world: &mut World, _system_meta: &mut SystemMeta, config: Self::Config) -> Self {
+        Self(config.unwrap_or_else(|| T::from_world(world)))
+    }
+
+    fn apply(&mut self, world: &mut World) {
+        self.0.apply(world);
+    }
+
+    fn default_config() -> Option<T> {
+        None
+    }
+}
+
+impl<'w, 's, T: SystemBuffer> SystemParamFetch<'w, 's> for DeferredState<T> {
+    type Item = Deferred<'s, T>;
+
+    #[inline]
+    unsafe fn get_param(
+        state: &'s mut Self,
+        _system_meta: &SystemMeta,
+        _world: &'w World,
+        _change_tick: Tick,
+    ) -> Self::Item {
+        Deferred(&mut state.0)
+    }
+}
+
 /// A [`SystemParam`] that grants access to the entities that had their `T` [`Component`] removed.
 ///
+/// Like an [`EventReader`](crate::event::EventReader), each system keeps track of the removals
+/// it has already seen, so it only sees the removals that happened since it last ran.
+///
 /// Note that this does not allow you to see which data existed before removal.
-/// If you need this, you will need to track the component data value on your own,
-/// using a regularly scheduled system that requests `Query<(Entity, &T), Changed<T>>`
-/// and stores the data somewhere safe to later cross-reference.
+/// If you need this, use [`RemovedValues`] instead.
 ///
 /// If you are using `bevy_ecs` as a standalone crate,
 /// note that the `RemovedComponents` list will not be automatically cleared for you,
@@ -655,15 +739,14 @@ impl<'w, 's, T: Resource + FromWorld> SystemParamFetch<'w, 's> for LocalState<T>
 /// # react_on_removal.system();
 /// ```
 pub struct RemovedComponents<'a, T: Component> {
-    world: &'a World,
-    component_id: ComponentId,
+    removed: &'a [Entity],
     marker: PhantomData<T>,
 }
 
 impl<'a, T: Component> RemovedComponents<'a, T> {
     /// Returns an iterator over the entities that had their `T` [`Component`] removed.
     pub fn iter(&self) -> std::iter::Cloned<std::slice::Iter<'_, Entity>> {
-        self.world.removed_with_id(self.component_id)
+        self.removed.iter().cloned()
     }
 }
 
@@ -673,6 +756,7 @@ unsafe impl<T: Component> ReadOnlySystemParamFetch for RemovedComponentsState<T>
 /// The [`SystemParamState`] of [`RemovedComponents<T>`].
 pub struct RemovedComponentsState<T> {
     component_id: ComponentId,
+    cursor: RemovalCursor,
     marker: PhantomData<T>,
 }
 
@@ -688,6 +772,7 @@ unsafe impl<T: Component> SystemParamState for RemovedComponentsState<T> {
     fn init(world: &mut World, _system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
         Self {
             component_id: world.init_component::<T>(),
+            cursor: RemovalCursor::default(),
             marker: PhantomData,
         }
     }
@@ -703,14 +788,134 @@ impl<'w, 's, T: Component> SystemParamFetch<'w, 's> for RemovedComponentsState<T
         state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
+        let removed = world
+            .removed_components
+            .get(state.component_id)
+            .map_or(&[][..], |removed| &removed[..]);
         RemovedComponents {
-            world,
-            component_id: state.component_id,
+            removed: state.cursor.read(world, removed),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A [`SystemParam`] that grants access to the values of removed `T` [`Component`]s, along with
+/// the entities they were removed from.
+///
+/// Using this parameter makes the [`World`] keep the values of removed `T` components, see
+/// [`World::track_removed_values`]. Values removed before the system is initialized are not seen.
+///
+/// Like [`RemovedComponents`], each system only sees the values removed since it last ran. The
+/// values are dropped on the next call to [`World::clear_trackers`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::system::RemovedValues;
+///
+/// #[derive(Component)]
+/// struct NetworkId(u64);
+///
+/// fn release_network_ids_system(removed: RemovedValues<NetworkId>) {
+///     for (entity, network_id) in removed.iter() {
+///         println!("{:?} released network id {}", entity, network_id.0);
+///     }
+/// }
+/// # release_network_ids_system.system();
+/// ```
+pub struct RemovedValues<'a, T: Component> {
+    removed: &'a [(Entity, T)],
+}
+
+impl<'a, T: Component> RemovedValues<'a, T> {
+    /// Returns an iterator over the removed `T` [`Component`]s and the entities they were removed
+    /// from.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> + '_ {
+        self.removed.iter().map(|(entity, value)| (*entity, value))
+    }
+
+    /// Returns the number of removed values.
+    pub fn len(&self) -> usize {
+        self.removed.len()
+    }
+
+    /// Returns `true` if no values were removed.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty()
+    }
+}
+
+// SAFE: Only reads World components
+unsafe impl<T: Component> ReadOnlySystemParamFetch for RemovedValuesState<T> {}
+
+/// The [`SystemParamState`] of [`RemovedValues<T>`].
+pub struct RemovedValuesState<T> {
+    component_id: ComponentId,
+    cursor: RemovalCursor,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T: Component> SystemParam for RemovedValues<'a, T> {
+    type Fetch = RemovedValuesState<T>;
+}
+
+// SAFE: no component access. removed values can be read in parallel and are never mutably
+// borrowed during system execution
+unsafe impl<T: Component> SystemParamState for RemovedValuesState<T> {
+    type Config = ();
+
+    fn init(world: &mut World, _system_meta: &mut SystemMeta, _config: Self::Config) -> Self {
+        world.track_removed_values::<T>();
+        Self {
+            component_id: world.init_component::<T>(),
+            cursor: RemovalCursor::default(),
             marker: PhantomData,
         }
     }
+
+    fn default_config() {}
+}
+
+impl<'w, 's, T: Component> SystemParamFetch<'w, 's> for RemovedValuesState<T> {
+    type Item = RemovedValues<'w, T>;
+
+    #[inline]
+    unsafe fn get_param(
+        state: &'s mut Self,
+        _system_meta: &SystemMeta,
+        world: &'w World,
+        _change_tick: Tick,
+    ) -> Self::Item {
+        let removed = world.removed_values_with_id::<T>(state.component_id);
+        RemovedValues {
+            removed: state.cursor.read(world, removed),
+        }
+    }
+}
+
+/// The position of a system in the removed components of a [`World`], which is reset by
+/// [`World::clear_trackers`].
+#[derive(Default)]
+struct RemovalCursor {
+    generation: u32,
+    read: usize,
+}
+
+impl RemovalCursor {
+    /// Returns the part of `removed` that was not read yet, and marks all of it as read.
+    fn read<'a, T>(&mut self, world: &World, removed: &'a [T]) -> &'a [T] {
+        let start = if self.generation == world.removal_generation {
+            self.read.min(removed.len())
+        } else {
+            0
+        };
+        self.generation = world.removal_generation;
+        self.read = removed.len();
+        &removed[start..]
+    }
 }
 
 /// Shared borrow of a non-[`Send`] resource.
@@ -728,8 +933,8 @@ impl<'w, 's, T: Component> SystemParamFetch<'w, 's> for RemovedComponentsState<T
 pub struct NonSend<'w, T: 'static> {
     pub(crate) value: &'w T,
     ticks: ComponentTicks,
-    last_change_tick: u32,
-    change_tick: u32,
+    last_change_tick: Tick,
+    change_tick: Tick,
 }
 
 // SAFE: Only reads a single World non-send resource
@@ -786,13 +991,13 @@ unsafe impl<T: 'static> SystemParamState for NonSendState<T> {
         system_meta.set_non_send();
 
         let component_id = world.initialize_non_send_resource::<T>();
-        let combined_access = system_meta.component_access_set.combined_access_mut();
-        if combined_access.has_write(component_id) {
-            panic!(
-                "error[B0002]: NonSend<{}> in system {} conflicts with a previous mutable resource access ({0}). Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        }
-        combined_access.add_read(component_id);
+        let mut access = FilteredAccess::default();
+        access.add_read(component_id);
+        system_meta.add_checked_resource_access(
+            &format!("NonSend<{}>", std::any::type_name::<T>()),
+            access,
+            world,
+        );
 
         let resource_archetype = world.archetypes.resource();
         let archetype_component_id = resource_archetype
@@ -818,7 +1023,7 @@ impl<'w, 's, T: 'static> SystemParamFetch<'w, 's> for NonSendState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world.validate_non_send_access::<T>();
         let column = world
@@ -869,7 +1074,7 @@ impl<'w, 's, T: 'static> SystemParamFetch<'w, 's> for OptionNonSendState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world.validate_non_send_access::<T>();
         world
@@ -902,17 +1107,13 @@ unsafe impl<T: 'static> SystemParamState for NonSendMutState<T> {
         system_meta.set_non_send();
 
         let component_id = world.initialize_non_send_resource::<T>();
-        let combined_access = system_meta.component_access_set.combined_access_mut();
-        if combined_access.has_write(component_id) {
-            panic!(
-                "error[B0002]: NonSendMut<{}> in system {} conflicts with a previous mutable resource access ({0}). Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        } else if combined_access.has_read(component_id) {
-            panic!(
-                "error[B0002]: NonSendMut<{}> in system {} conflicts with a previous immutable resource access ({0}). Consider removing the duplicate access.",
-                std::any::type_name::<T>(), system_meta.name);
-        }
-        combined_access.add_write(component_id);
+        let mut access = FilteredAccess::default();
+        access.add_write(component_id);
+        system_meta.add_checked_resource_access(
+            &format!("NonSendMut<{}>", std::any::type_name::<T>()),
+            access,
+            world,
+        );
 
         let resource_archetype = world.archetypes.resource();
         let archetype_component_id = resource_archetype
@@ -938,7 +1139,7 @@ impl<'w, 's, T: 'static> SystemParamFetch<'w, 's> for NonSendMutState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world.validate_non_send_access::<T>();
         let column = world
@@ -987,7 +1188,7 @@ impl<'w, 's, T: 'static> SystemParamFetch<'w, 's> for OptionNonSendMutState<T> {
         state: &'s mut Self,
         system_meta: &SystemMeta,
         world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         world.validate_non_send_access::<T>();
         world
@@ -1032,7 +1233,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for ArchetypesState {
         _state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         world.archetypes()
     }
@@ -1067,7 +1268,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for ComponentsState {
         _state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         world.components()
     }
@@ -1102,7 +1303,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for EntitiesState {
         _state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         world.entities()
     }
@@ -1137,7 +1338,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for BundlesState {
         _state: &'s mut Self,
         _system_meta: &SystemMeta,
         world: &'w World,
-        _change_tick: u32,
+        _change_tick: Tick,
     ) -> Self::Item {
         world.bundles()
     }
@@ -1145,8 +1346,8 @@ impl<'w, 's> SystemParamFetch<'w, 's> for BundlesState {
 
 #[derive(Debug)]
 pub struct SystemChangeTick {
-    pub last_change_tick: u32,
-    pub change_tick: u32,
+    pub last_change_tick: Tick,
+    pub change_tick: Tick,
 }
 
 // SAFE: Only reads internal system state
@@ -1176,7 +1377,7 @@ impl<'w, 's> SystemParamFetch<'w, 's> for SystemChangeTickState {
         _state: &'s mut Self,
         system_meta: &SystemMeta,
         _world: &'w World,
-        change_tick: u32,
+        change_tick: Tick,
     ) -> Self::Item {
         SystemChangeTick {
             last_change_tick: system_meta.last_change_tick,
@@ -1205,7 +1406,7 @@ macro_rules! impl_system_param_tuple {
                 state: &'s mut Self,
                 system_meta: &SystemMeta,
                 world: &'w World,
-                change_tick: u32,
+                change_tick: Tick,
             ) -> Self::Item {
 
                 let ($($param,)*) = state;
@@ -1218,9 +1419,18 @@ macro_rules! impl_system_param_tuple {
         unsafe impl<$($param: SystemParamState),*> SystemParamState for ($($param,)*) {
             type Config = ($(<$param as SystemParamState>::Config,)*);
             #[inline]
+            #[allow(clippy::let_unit_value)]
             fn init(_world: &mut World, _system_meta: &mut SystemMeta, config: Self::Config) -> Self {
                 let ($($param,)*) = config;
-                (($($param::init(_world, _system_meta, $param),)*))
+                let _outermost = _system_meta.enter_param_list();
+                let state = (($({
+                    if _outermost {
+                        _system_meta.next_param();
+                    }
+                    $param::init(_world, _system_meta, $param)
+                },)*));
+                _system_meta.exit_param_list();
+                state
             }
 
             #[inline]
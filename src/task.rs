@@ -0,0 +1,237 @@
+//! Futures whose results are fed back into the [`World`].
+//!
+//! Tasks are spawned with [`Commands::spawn_task`] and stored in the [`AsyncTasks`] resource.
+//! Every [`SystemStage`](crate::schedule::SystemStage) polls them on the current thread with
+//! [`poll_tasks`] after applying its systems' commands, so no thread pool is needed and tasks
+//! also run on `wasm32`. The callbacks of completed tasks are applied as commands. While a task
+//! is in flight, its entity has a [`PendingTask`] component that systems can query.
+
+use crate::{
+    component::{Component, SparseStorage},
+    entity::Entity,
+    system::{Command, CommandQueue, Commands, EntityCommands},
+    world::{Mut, World},
+};
+use bevy_utils::BoxedFuture;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+};
+
+type TaskCallback = Box<dyn FnOnce(&mut World)>;
+
+/// Marks an entity whose task spawned with [`Commands::spawn_task`] has not completed yet.
+///
+/// The entity is despawned after the task's callback has run. Despawning the entity (or removing
+/// this component) before that cancels the task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingTask {
+    output: &'static str,
+}
+
+impl PendingTask {
+    /// Returns the type name of the value the task produces.
+    pub fn output_type_name(&self) -> &'static str {
+        self.output
+    }
+}
+
+impl Component for PendingTask {
+    type Storage = SparseStorage;
+}
+
+struct TaskWaker {
+    woken: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+    }
+}
+
+struct TaskFuture(BoxedFuture<'static, TaskCallback>);
+
+// SAFE: the future is only ever accessed by value or through `&mut AsyncTasks`, so sharing a
+// reference to it between threads cannot be used to touch it.
+unsafe impl Sync for TaskFuture {}
+
+// SAFE: wasm32 has no threads, so the future can never be moved to another thread.
+#[cfg(target_arch = "wasm32")]
+unsafe impl Send for TaskFuture {}
+
+struct Task {
+    entity: Entity,
+    future: TaskFuture,
+    waker: Arc<TaskWaker>,
+}
+
+/// Resource holding the futures spawned with [`Commands::spawn_task`].
+///
+/// Futures are polled by [`poll_tasks`], which every
+/// [`SystemStage`](crate::schedule::SystemStage) runs after applying its systems' commands.
+#[derive(Default)]
+pub struct AsyncTasks {
+    tasks: Vec<Task>,
+}
+
+impl AsyncTasks {
+    fn push(&mut self, entity: Entity, future: TaskFuture) {
+        self.tasks.push(Task {
+            entity,
+            future,
+            waker: Arc::new(TaskWaker {
+                woken: AtomicBool::new(true),
+            }),
+        });
+    }
+
+    /// Returns the number of tasks that have not completed yet.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if there are no pending tasks.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Polls every task that has been woken since it was last polled and returns the
+    /// callbacks of the tasks that completed, in the order they were spawned.
+    fn poll(&mut self) -> Vec<(Entity, TaskCallback)> {
+        let mut completed = Vec::new();
+        self.tasks.retain_mut(|task| {
+            if !task.waker.woken.swap(false, Ordering::AcqRel) {
+                return true;
+            }
+            let waker = Waker::from(task.waker.clone());
+            let mut context = Context::from_waker(&waker);
+            match task.future.0.as_mut().poll(&mut context) {
+                Poll::Ready(callback) => {
+                    completed.push((task.entity, callback));
+                    false
+                }
+                Poll::Pending => true,
+            }
+        });
+        completed
+    }
+}
+
+/// Polls the [`AsyncTasks`] and applies the callbacks of completed tasks to the world as
+/// commands.
+///
+/// This runs automatically at the end of every [`SystemStage`](crate::schedule::SystemStage)
+/// that applies its command buffers. It can also be added as an exclusive system to apply
+/// completed tasks at another point of the schedule.
+///
+/// Tasks whose entity no longer has a [`PendingTask`] component are dropped without being
+/// polled.
+pub fn poll_tasks(world: &mut World) {
+    if !world.contains_resource::<AsyncTasks>() {
+        return;
+    }
+    let completed = world.resource_scope(|world, mut tasks: Mut<AsyncTasks>| {
+        tasks.tasks.retain(|task| is_pending(world, task.entity));
+        tasks.poll()
+    });
+    if completed.is_empty() {
+        return;
+    }
+    let mut queue = CommandQueue::default();
+    for (entity, callback) in completed {
+        queue.push(CompleteTask { entity, callback });
+    }
+    queue.apply(world);
+}
+
+/// Runs the callback of a completed task and despawns its entity, unless the task was cancelled
+/// in the meantime.
+struct CompleteTask {
+    entity: Entity,
+    callback: TaskCallback,
+}
+
+// SAFE: `CompleteTask` is only pushed to the local queue of `poll_tasks`, which is applied on
+// the thread that created it.
+unsafe impl Send for CompleteTask {}
+unsafe impl Sync for CompleteTask {}
+
+impl Command for CompleteTask {
+    fn write(self, world: &mut World) {
+        if is_pending(world, self.entity) {
+            (self.callback)(world);
+            world.despawn(self.entity);
+        }
+    }
+}
+
+fn is_pending(world: &World, entity: Entity) -> bool {
+    world
+        .get_entity(entity)
+        .is_some_and(|entity| entity.contains::<PendingTask>())
+}
+
+struct SpawnTask {
+    entity: Entity,
+    future: TaskFuture,
+}
+
+impl Command for SpawnTask {
+    fn write(self, world: &mut World) {
+        world
+            .get_resource_or_insert_with(AsyncTasks::default)
+            .push(self.entity, self.future);
+    }
+}
+
+impl<'w, 's> Commands<'w, 's> {
+    /// Spawns an entity with a [`PendingTask`] component and schedules `future` on the
+    /// [`AsyncTasks`] resource.
+    ///
+    /// The future is polled by [`poll_tasks`] at the end of every
+    /// [`SystemStage`](crate::schedule::SystemStage). Once it completes, `callback` is applied as
+    /// a command with its output and the world, after which the task entity is despawned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// struct Level(String);
+    ///
+    /// fn load_level(mut commands: Commands) {
+    ///     commands.spawn_task(Box::pin(async { String::from("level 1") }), |name, world| {
+    ///         world.insert_resource(Level(name));
+    ///     });
+    /// }
+    /// # load_level.system();
+    /// ```
+    pub fn spawn_task<'a, T: 'static>(
+        &'a mut self,
+        future: BoxedFuture<'static, T>,
+        callback: impl FnOnce(T, &mut World) + Send + 'static,
+    ) -> EntityCommands<'w, 's, 'a> {
+        let task: BoxedFuture<'static, TaskCallback> = Box::pin(async move {
+            let output = future.await;
+            Box::new(move |world: &mut World| callback(output, world)) as TaskCallback
+        });
+        let mut entity_commands = self.spawn();
+        entity_commands.insert(PendingTask {
+            output: std::any::type_name::<T>(),
+        });
+        let entity = entity_commands.id();
+        entity_commands.commands().add(SpawnTask {
+            entity,
+            future: TaskFuture(task),
+        });
+        entity_commands
+    }
+}
\ No newline at end of file
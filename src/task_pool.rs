@@ -0,0 +1,207 @@
+//! Task pools used to run batches of work in parallel, such as [`Query::par_for_each`].
+//!
+//! The pool used by a [`World`] can be replaced with [`World::set_task_pool`], e.g. to share the
+//! threads of an existing thread pool.
+//!
+//! [`Query::par_for_each`]: crate::system::Query::par_for_each
+//! [`World`]: crate::world::World
+//! [`World::set_task_pool`]: crate::world::World::set_task_pool
+
+use std::{
+    any::Any,
+    collections::VecDeque,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+};
+
+/// A unit of work run by a [`TaskPool`].
+pub type Job<'scope> = Box<dyn FnOnce() + Send + 'scope>;
+
+/// Runs batches of [`Job`]s, possibly in parallel.
+pub trait TaskPool: Send + Sync {
+    /// Runs every job in `jobs` and returns once all of them have completed.
+    ///
+    /// Jobs may borrow from the caller's stack, so they must not outlive this call.
+    fn scope<'scope>(&self, jobs: Vec<Job<'scope>>);
+
+    /// Returns the number of jobs this pool can run at the same time.
+    fn thread_num(&self) -> usize;
+}
+
+/// A [`TaskPool`] that runs jobs one after another on the calling thread.
+///
+/// This is the default task pool on `wasm32`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SerialTaskPool;
+
+impl TaskPool for SerialTaskPool {
+    fn scope<'scope>(&self, jobs: Vec<Job<'scope>>) {
+        for job in jobs {
+            job();
+        }
+    }
+
+    fn thread_num(&self) -> usize {
+        1
+    }
+}
+
+/// A [`TaskPool`] that runs jobs on a set of persistent worker threads.
+///
+/// The calling thread works on the jobs too, so `threads - 1` worker threads are spawned the first
+/// time [`TaskPool::scope`] needs them and are reused by every later call. Clones of a pool share
+/// its workers, which exit once the last clone is dropped. This is the default task pool on every
+/// platform except `wasm32`.
+#[derive(Debug, Clone)]
+pub struct ThreadTaskPool {
+    threads: usize,
+    workers: Arc<OnceLock<Workers>>,
+}
+
+impl ThreadTaskPool {
+    /// Creates a pool that runs jobs on up to `threads` threads.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            workers: Default::default(),
+        }
+    }
+
+    fn workers(&self) -> &Workers {
+        self.workers
+            .get_or_init(|| Workers::spawn(self.threads - 1))
+    }
+}
+
+impl Default for ThreadTaskPool {
+    /// Creates a pool with one thread per available CPU core.
+    fn default() -> Self {
+        Self::new(
+            std::thread::available_parallelism()
+                .map(NonZeroUsize::get)
+                .unwrap_or(1),
+        )
+    }
+}
+
+impl TaskPool for ThreadTaskPool {
+    fn scope<'scope>(&self, jobs: Vec<Job<'scope>>) {
+        let threads = self.threads.min(jobs.len());
+        if threads <= 1 {
+            return SerialTaskPool.scope(jobs);
+        }
+
+        let scope = Arc::new(Scope {
+            remaining: Mutex::new(jobs.len()),
+            // SAFETY: the jobs are only erased to `'static` so they can be handed to the workers.
+            // Every job is run or dropped before `Scope::wait` returns below, so none of them
+            // outlives `'scope`. Workers only keep the `Scope` itself, which no longer holds any
+            // job by then.
+            jobs: Mutex::new(
+                jobs.into_iter()
+                    .map(|job| unsafe { std::mem::transmute::<Job<'scope>, Job<'static>>(job) })
+                    .collect(),
+            ),
+            done: Condvar::new(),
+            panic: Mutex::new(None),
+        });
+        let workers = self.workers();
+        for _ in 1..threads {
+            workers.sender.send(scope.clone()).unwrap();
+        }
+        // The calling thread takes jobs as well, so the scope completes even if every worker is
+        // busy, e.g. when a job itself calls `scope` on the same pool.
+        scope.run_jobs();
+        scope.wait();
+    }
+
+    fn thread_num(&self) -> usize {
+        self.threads
+    }
+}
+
+/// The worker threads of a [`ThreadTaskPool`], waiting for [`Scope`]s to help with.
+#[derive(Debug)]
+struct Workers {
+    // Dropping the sender stops the workers.
+    sender: Sender<Arc<Scope>>,
+}
+
+impl Workers {
+    fn spawn(threads: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Arc<Scope>>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for i in 0..threads {
+            let receiver = receiver.clone();
+            std::thread::Builder::new()
+                .name(format!("ThreadTaskPool ({})", i))
+                .spawn(move || loop {
+                    // The lock is released before helping, so other workers can pick up the
+                    // scope as well.
+                    let scope = receiver.lock().unwrap().recv();
+                    match scope {
+                        Ok(scope) => scope.run_jobs(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to spawn a task pool thread");
+        }
+        Self { sender }
+    }
+}
+
+/// The jobs of a single call to [`TaskPool::scope`] on a [`ThreadTaskPool`].
+struct Scope {
+    jobs: Mutex<VecDeque<Job<'static>>>,
+    remaining: Mutex<usize>,
+    done: Condvar,
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl Scope {
+    /// Runs jobs until none are left to take.
+    fn run_jobs(&self) {
+        loop {
+            // The lock is released before the job runs, so other threads can take the next one.
+            let job = self.jobs.lock().unwrap().pop_front();
+            let job = match job {
+                Some(job) => job,
+                None => break,
+            };
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(job)) {
+                self.panic.lock().unwrap().get_or_insert(payload);
+            }
+            let mut remaining = self.remaining.lock().unwrap();
+            *remaining -= 1;
+            if *remaining == 0 {
+                self.done.notify_all();
+            }
+        }
+    }
+
+    /// Blocks until every job has completed, then resumes the first panic raised by a job.
+    fn wait(&self) {
+        let mut remaining = self.remaining.lock().unwrap();
+        while *remaining > 0 {
+            remaining = self.done.wait(remaining).unwrap();
+        }
+        drop(remaining);
+        if let Some(payload) = self.panic.lock().unwrap().take() {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn default_task_pool() -> Box<dyn TaskPool> {
+    Box::new(ThreadTaskPool::default())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn default_task_pool() -> Box<dyn TaskPool> {
+    Box::new(SerialTaskPool)
+}
@@ -1,11 +1,12 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
     bundle::{Bundle, BundleInfo},
-    change_detection::Ticks,
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    change_detection::{Ticks, TicksRef},
+    component::{Component, ComponentId, ComponentTicks, Components, StorageType, Tick},
     entity::{Entities, Entity, EntityLocation},
+    index::IndexHooks,
     storage::{SparseSet, Storages},
-    world::{Mut, World},
+    world::{store_or_drop_removed, Mut, Ref, World},
 };
 use std::any::TypeId;
 
@@ -69,14 +70,37 @@ impl<'w> EntityRef<'w> {
         }
     }
 
+    /// Gets the component of type `T` along with its change ticks, so it can be checked for
+    /// changes since the last time the world's systems ran.
+    #[inline]
+    pub fn get_ref<T: Component>(&self) -> Option<Ref<'w, T>> {
+        // SAFE: entity location is valid and returned component is of type T
+        unsafe {
+            get_component_and_ticks_with_type(
+                self.world,
+                TypeId::of::<T>(),
+                self.entity,
+                self.location,
+            )
+            .map(|(value, ticks)| Ref {
+                value: &*value.cast::<T>(),
+                ticks: TicksRef {
+                    component_ticks: &*ticks,
+                    last_change_tick: self.world.last_change_tick(),
+                    change_tick: self.world.read_change_tick(),
+                },
+            })
+        }
+    }
+
     /// # Safety
     /// This allows aliased mutability. You must make sure this call does not result in multiple
     /// mutable references to the same component
     #[inline]
     pub unsafe fn get_unchecked_mut<T: Component>(
         &self,
-        last_change_tick: u32,
-        change_tick: u32,
+        last_change_tick: Tick,
+        change_tick: Tick,
     ) -> Option<Mut<'w, T>> {
         get_component_and_ticks_with_type(self.world, TypeId::of::<T>(), self.entity, self.location)
             .map(|(value, ticks)| Mut {
@@ -239,6 +263,10 @@ impl<'w> EntityMut<'w> {
         let old_archetype = &mut archetypes[old_location.archetype_id];
         let mut bundle_components = bundle_info.component_ids.iter().cloned();
         let entity = self.entity;
+        let index_hooks = IndexHooks::collect(
+            &self.world.index_hooks,
+            bundle_info.component_ids.iter().cloned(),
+        );
         // SAFE: bundle components are iterated in order, which guarantees that the component type
         // matches
         let result = unsafe {
@@ -270,6 +298,10 @@ impl<'w> EntityMut<'w> {
             );
         }
 
+        for hooks in index_hooks {
+            (hooks.remove)(self.world, entity);
+        }
+
         Some(result)
     }
 
@@ -361,35 +393,102 @@ impl<'w> EntityMut<'w> {
 
         let old_archetype = &mut archetypes[old_location.archetype_id];
         let entity = self.entity;
-        for component_id in bundle_info.component_ids.iter().cloned() {
-            if old_archetype.contains(component_id) {
-                removed_components
-                    .get_or_insert_with(component_id, Vec::new)
-                    .push(entity);
-
-                // Make sure to drop components stored in sparse sets.
-                // Dense components are dropped later in `move_to_and_drop_missing_unchecked`.
-                if let Some(StorageType::SparseSet) = old_archetype.get_storage_type(component_id) {
-                    storages
-                        .sparse_sets
-                        .get_mut(component_id)
-                        .unwrap()
-                        .remove(entity);
+        let index_hooks = IndexHooks::collect(
+            &self.world.index_hooks,
+            bundle_info
+                .component_ids
+                .iter()
+                .cloned()
+                .filter(|component_id| old_archetype.contains(*component_id)),
+        );
+        let removed_values = &mut self.world.removed_values;
+        let keep_values = bundle_info.component_ids.iter().any(|component_id| {
+            old_archetype.contains(*component_id) && removed_values.contains(*component_id)
+        });
+        if keep_values {
+            for component_id in bundle_info.component_ids.iter().cloned() {
+                if old_archetype.contains(component_id) {
+                    // SAFE: entity location is valid, the taken component is moved or dropped
+                    // right away and the table row is removed below
+                    unsafe {
+                        let value = take_component(
+                            components,
+                            storages,
+                            old_archetype,
+                            removed_components,
+                            component_id,
+                            entity,
+                            old_location,
+                        );
+                        store_or_drop_removed(
+                            removed_values,
+                            components,
+                            component_id,
+                            entity,
+                            value,
+                        );
+                    }
+                }
+            }
+        } else {
+            for component_id in bundle_info.component_ids.iter().cloned() {
+                if old_archetype.contains(component_id) {
+                    removed_components
+                        .get_or_insert_with(component_id, Vec::new)
+                        .push(entity);
+
+                    // Make sure to drop components stored in sparse sets and tags.
+                    // Dense components are dropped later in `move_to_and_drop_missing_unchecked`.
+                    match old_archetype.get_storage_type(component_id) {
+                        Some(StorageType::SparseSet) => {
+                            storages
+                                .sparse_sets
+                                .get_mut(component_id)
+                                .unwrap()
+                                .remove(entity);
+                        }
+                        Some(StorageType::Tag) => {
+                            // SAFE: component_id exists, and the tag is removed from the entity
+                            // below
+                            unsafe {
+                                let component_info = components.get_info_unchecked(component_id);
+                                (component_info.drop())(component_info.tag_ptr());
+                            }
+                        }
+                        _ => {}
+                    }
                 }
             }
         }
 
         unsafe {
-            Self::move_entity_from_remove::<true>(
-                entity,
-                &mut self.location,
-                old_location.archetype_id,
-                old_location,
-                entities,
-                archetypes,
-                storages,
-                new_archetype_id,
-            )
+            if keep_values {
+                Self::move_entity_from_remove::<false>(
+                    entity,
+                    &mut self.location,
+                    old_location.archetype_id,
+                    old_location,
+                    entities,
+                    archetypes,
+                    storages,
+                    new_archetype_id,
+                )
+            } else {
+                Self::move_entity_from_remove::<true>(
+                    entity,
+                    &mut self.location,
+                    old_location.archetype_id,
+                    old_location,
+                    entities,
+                    archetypes,
+                    storages,
+                    new_archetype_id,
+                )
+            }
+        }
+
+        for hooks in index_hooks {
+            (hooks.remove)(self.world, entity);
         }
     }
 
@@ -410,8 +509,10 @@ impl<'w> EntityMut<'w> {
             .expect("entity should exist at this point.");
         let table_row;
         let moved_entity;
+        let index_hooks;
         {
             let archetype = &mut world.archetypes[location.archetype_id];
+            index_hooks = IndexHooks::collect(&world.index_hooks, archetype.components());
             for component_id in archetype.components() {
                 let removed_components = world
                     .removed_components
@@ -424,14 +525,70 @@ impl<'w> EntityMut<'w> {
             }
             table_row = remove_result.table_row;
 
-            for component_id in archetype.sparse_set_components() {
-                let sparse_set = world.storages.sparse_sets.get_mut(*component_id).unwrap();
-                sparse_set.remove(self.entity);
+            let keep_values = archetype
+                .components()
+                .any(|component_id| world.removed_values.contains(component_id));
+            if keep_values {
+                for component_id in archetype.sparse_set_components() {
+                    let sparse_set = world.storages.sparse_sets.get_mut(*component_id).unwrap();
+                    let value = sparse_set.remove_and_forget(self.entity).unwrap();
+                    // SAFE: the value was removed from its sparse set and is not used again
+                    unsafe {
+                        store_or_drop_removed(
+                            &mut world.removed_values,
+                            &world.components,
+                            *component_id,
+                            self.entity,
+                            value,
+                        );
+                    }
+                }
+                for component_id in archetype.tag_components() {
+                    // SAFE: component_id exists, and the tag is gone with the entity
+                    unsafe {
+                        let component_info = world.components.get_info_unchecked(*component_id);
+                        store_or_drop_removed(
+                            &mut world.removed_values,
+                            &world.components,
+                            *component_id,
+                            self.entity,
+                            component_info.tag_ptr(),
+                        );
+                    }
+                }
+                let table = &mut world.storages.tables[archetype.table_id()];
+                for column in table.iter() {
+                    // SAFE: table rows stored in archetypes always exist, and the values are
+                    // forgotten by the table below
+                    unsafe {
+                        store_or_drop_removed(
+                            &mut world.removed_values,
+                            &world.components,
+                            column.component_id,
+                            self.entity,
+                            column.get_data_unchecked(table_row),
+                        );
+                    }
+                }
+                // SAFE: table rows stored in archetypes always exist
+                moved_entity = unsafe { table.swap_remove_and_forget_unchecked(table_row) };
+            } else {
+                for component_id in archetype.sparse_set_components() {
+                    let sparse_set = world.storages.sparse_sets.get_mut(*component_id).unwrap();
+                    sparse_set.remove(self.entity);
+                }
+                for component_id in archetype.tag_components() {
+                    // SAFE: component_id exists, and the tag is gone with the entity
+                    unsafe {
+                        let component_info = world.components.get_info_unchecked(*component_id);
+                        (component_info.drop())(component_info.tag_ptr());
+                    }
+                }
+                // SAFE: table rows stored in archetypes always exist
+                moved_entity = unsafe {
+                    world.storages.tables[archetype.table_id()].swap_remove_unchecked(table_row)
+                };
             }
-            // SAFE: table rows stored in archetypes always exist
-            moved_entity = unsafe {
-                world.storages.tables[archetype.table_id()].swap_remove_unchecked(table_row)
-            };
         };
 
         if let Some(moved_entity) = moved_entity {
@@ -439,6 +596,10 @@ impl<'w> EntityMut<'w> {
             world.archetypes[moved_location.archetype_id]
                 .set_entity_table_row(moved_location.index, table_row);
         }
+
+        for hooks in index_hooks {
+            (hooks.remove)(world, self.entity);
+        }
     }
 
     #[inline]
@@ -490,6 +651,9 @@ unsafe fn get_component(
             .sparse_sets
             .get(component_id)
             .and_then(|sparse_set| sparse_set.get(entity)),
+        StorageType::Tag => archetype
+            .contains(component_id)
+            .then(|| component_info.tag_ptr()),
     }
 }
 
@@ -521,6 +685,8 @@ unsafe fn get_component_and_ticks(
             .sparse_sets
             .get(component_id)
             .and_then(|sparse_set| sparse_set.get_with_ticks(entity)),
+        // tags have no change ticks
+        StorageType::Tag => None,
     }
 }
 
@@ -563,6 +729,7 @@ unsafe fn take_component(
             .unwrap()
             .remove_and_forget(entity)
             .unwrap(),
+        StorageType::Tag => component_info.tag_ptr(),
     }
 }
 
@@ -641,11 +808,13 @@ unsafe fn remove_bundle_from_archetype(
     } else {
         let mut next_table_components;
         let mut next_sparse_set_components;
+        let mut next_tag_components;
         let next_table_id;
         {
             let current_archetype = &mut archetypes[archetype_id];
             let mut removed_table_components = Vec::new();
             let mut removed_sparse_set_components = Vec::new();
+            let mut removed_tag_components = Vec::new();
             for component_id in bundle_info.component_ids.iter().cloned() {
                 if current_archetype.contains(component_id) {
                     // SAFE: bundle components were already initialized by bundles.get_info
@@ -653,6 +822,7 @@ unsafe fn remove_bundle_from_archetype(
                     match component_info.storage_type() {
                         StorageType::Table => removed_table_components.push(component_id),
                         StorageType::SparseSet => removed_sparse_set_components.push(component_id),
+                        StorageType::Tag => removed_tag_components.push(component_id),
                     }
                 } else if !intersection {
                     // a component in the bundle was not present in the entity's archetype, so this
@@ -669,6 +839,7 @@ unsafe fn remove_bundle_from_archetype(
             // components are already sorted
             removed_table_components.sort();
             removed_sparse_set_components.sort();
+            removed_tag_components.sort();
             next_table_components = current_archetype.table_components().to_vec();
             next_sparse_set_components = current_archetype.sparse_set_components().to_vec();
             sorted_remove(&mut next_table_components, &removed_table_components);
@@ -676,6 +847,8 @@ unsafe fn remove_bundle_from_archetype(
                 &mut next_sparse_set_components,
                 &removed_sparse_set_components,
             );
+            next_tag_components = current_archetype.tag_components().to_vec();
+            sorted_remove(&mut next_tag_components, &removed_tag_components);
 
             next_table_id = if removed_table_components.is_empty() {
                 current_archetype.table_id()
@@ -691,6 +864,7 @@ unsafe fn remove_bundle_from_archetype(
             next_table_id,
             next_table_components,
             next_sparse_set_components,
+            next_tag_components,
         );
         Some(new_archetype_id)
     };
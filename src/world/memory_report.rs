@@ -0,0 +1,197 @@
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentId, StorageType},
+    storage::TableId,
+    world::World,
+};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
+
+/// An amount of memory, in bytes.
+///
+/// `used` counts the bytes holding live values, and `reserved` the bytes allocated for them,
+/// including unused capacity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub used: usize,
+    pub reserved: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the memory used by the elements of `vec`, not counting the memory they own.
+    pub(crate) fn of_vec<T>(vec: &Vec<T>) -> Self {
+        Self::of_items::<T>(vec.len(), vec.capacity())
+    }
+
+    /// Returns the memory used by `len` items of type `T` in an allocation of `capacity` items.
+    pub(crate) fn of_items<T>(len: usize, capacity: usize) -> Self {
+        let size = std::mem::size_of::<T>();
+        Self {
+            used: len * size,
+            reserved: capacity * size,
+        }
+    }
+}
+
+impl Add for MemoryUsage {
+    type Output = MemoryUsage;
+
+    fn add(self, other: MemoryUsage) -> MemoryUsage {
+        MemoryUsage {
+            used: self.used + other.used,
+            reserved: self.reserved + other.reserved,
+        }
+    }
+}
+
+impl AddAssign for MemoryUsage {
+    fn add_assign(&mut self, other: MemoryUsage) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for MemoryUsage {
+    fn sum<I: Iterator<Item = MemoryUsage>>(iter: I) -> MemoryUsage {
+        iter.fold(MemoryUsage::default(), Add::add)
+    }
+}
+
+/// The memory used by the values of a component or resource.
+#[derive(Clone, Debug)]
+pub struct ComponentMemoryUsage {
+    pub id: ComponentId,
+    pub name: String,
+    pub storage_type: StorageType,
+    pub is_resource: bool,
+    /// The memory used by the values and their change ticks. For [`StorageType::SparseSet`]
+    /// components, this also counts the sparse set's entity lists.
+    pub usage: MemoryUsage,
+}
+
+/// The memory used by an [`Archetype`](crate::archetype::Archetype) itself: its entity lists,
+/// edges and component metadata.
+#[derive(Clone, Debug)]
+pub struct ArchetypeMemoryUsage {
+    pub id: ArchetypeId,
+    pub entity_count: usize,
+    pub usage: MemoryUsage,
+}
+
+/// The memory used by a [`Table`](crate::storage::Table) itself: its entity list and column
+/// metadata. The columns are counted in [`ComponentMemoryUsage`].
+#[derive(Clone, Debug)]
+pub struct TableMemoryUsage {
+    pub id: TableId,
+    pub entity_count: usize,
+    pub usage: MemoryUsage,
+}
+
+/// A breakdown of the memory used by a [`World`], see [`World::memory_report`].
+///
+/// Each byte is counted in at most one place, so the parts can be summed up. The overhead of hash
+/// maps and of the world's smaller bookkeeping structures is not counted.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryReport {
+    pub components: Vec<ComponentMemoryUsage>,
+    pub archetypes: Vec<ArchetypeMemoryUsage>,
+    pub tables: Vec<TableMemoryUsage>,
+    /// The memory used by the metadata of all entities.
+    pub entities: MemoryUsage,
+}
+
+impl MemoryReport {
+    /// Returns the memory used by all the parts of the report.
+    pub fn total(&self) -> MemoryUsage {
+        self.components
+            .iter()
+            .map(|component| component.usage)
+            .chain(self.archetypes.iter().map(|archetype| archetype.usage))
+            .chain(self.tables.iter().map(|table| table.usage))
+            .sum::<MemoryUsage>()
+            + self.entities
+    }
+}
+
+impl World {
+    /// Returns a breakdown of the memory used by the components, resources, archetypes, tables
+    /// and entities of this world.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn().insert(Position(0.0, 0.0));
+    ///
+    /// let report = world.memory_report();
+    /// let position = report
+    ///     .components
+    ///     .iter()
+    ///     .find(|component| component.name.ends_with("Position"))
+    ///     .unwrap();
+    /// assert!(position.usage.used >= std::mem::size_of::<Position>());
+    /// assert!(report.total().reserved >= report.total().used);
+    /// ```
+    pub fn memory_report(&self) -> MemoryReport {
+        let mut component_usages = vec![MemoryUsage::default(); self.components.len()];
+        let mut tables = Vec::with_capacity(self.storages.tables.len());
+        for index in 0..self.storages.tables.len() {
+            let id = TableId::new(index);
+            let table = match self.storages.tables.get(id) {
+                Some(table) => table,
+                None => continue,
+            };
+            for column in table.iter() {
+                component_usages[column.component_id.index()] += column.memory_usage();
+            }
+            tables.push(TableMemoryUsage {
+                id,
+                entity_count: table.len(),
+                usage: table.memory_usage(),
+            });
+        }
+        for (component_id, sparse_set) in self.storages.sparse_sets.iter() {
+            component_usages[component_id.index()] += sparse_set.memory_usage();
+        }
+        for column in self.archetypes.resource().unique_components.values() {
+            component_usages[column.component_id.index()] += column.memory_usage();
+        }
+
+        let components = component_usages
+            .into_iter()
+            .enumerate()
+            .map(|(index, usage)| {
+                let id = ComponentId::new(index);
+                // SAFE: every component id below `Components::len` is valid
+                let info = unsafe { self.components.get_info_unchecked(id) };
+                ComponentMemoryUsage {
+                    id,
+                    name: info.name().to_string(),
+                    storage_type: info.storage_type(),
+                    is_resource: self.archetypes.resource().unique_components.contains(id),
+                    usage,
+                }
+            })
+            .collect();
+        let archetypes = self
+            .archetypes
+            .iter()
+            .map(|archetype| ArchetypeMemoryUsage {
+                id: archetype.id(),
+                entity_count: archetype.len(),
+                usage: archetype.memory_usage(),
+            })
+            .collect();
+
+        MemoryReport {
+            components,
+            archetypes,
+            tables,
+            entities: self.entities.memory_usage(),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 mod entity_ref;
+mod memory_report;
+mod removed_values;
 mod spawn_batch;
 mod world_cell;
 
-pub use crate::change_detection::Mut;
+pub use crate::change_detection::{Mut, Ref};
 pub use entity_ref::*;
+pub use memory_report::*;
 pub use spawn_batch::*;
 pub use world_cell::*;
 
@@ -11,21 +14,23 @@ use crate::{
     archetype::{ArchetypeComponentId, ArchetypeComponentInfo, ArchetypeId, Archetypes},
     bundle::{Bundle, BundleInserter, BundleSpawner, Bundles},
     change_detection::Ticks,
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    component::{
+        AtomicTick, Component, ComponentId, ComponentTicks, Components, StorageType, Tick,
+    },
     entity::{AllocAtWithoutReplacement, Entities, Entity},
+    index::IndexHooks,
     query::{FilterFetch, QueryState, WorldQuery},
-    storage::{Column, SparseSet, Storages},
+    storage::{Column, SparseSet, Storages, TableId},
     system::Resource,
+    task_pool::{self, TaskPool},
 };
-use std::{
-    any::TypeId,
-    fmt,
-    sync::atomic::{AtomicU32, Ordering},
-};
+use fixedbitset::FixedBitSet;
+use std::{any::TypeId, fmt, sync::atomic::Ordering};
 
 mod identifier;
 
 pub use identifier::WorldId;
+pub(crate) use removed_values::{store_or_drop_removed, RemovedValueBuffer};
 /// Stores and exposes operations on [entities](Entity), [components](Component), resources,
 /// and their associated metadata.
 ///
@@ -76,11 +81,26 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) removed_components: SparseSet<ComponentId, Vec<Entity>>,
+    /// Values of removed components, for the components whose removed values are tracked.
+    pub(crate) removed_values: SparseSet<ComponentId, Box<dyn RemovedValueBuffer>>,
+    /// Incremented by [`World::clear_trackers`], so readers of removed components know when
+    /// their position is no longer valid.
+    pub(crate) removal_generation: u32,
+    /// Hooks that keep the [`ComponentIndex`](crate::index::ComponentIndex) of each indexed
+    /// component up to date.
+    pub(crate) index_hooks: SparseSet<ComponentId, IndexHooks>,
+    /// The number of consecutive [`World::prune_empty_storage`] calls during which each
+    /// archetype was empty, indexed by archetype id.
+    empty_archetype_frames: Vec<u32>,
+    /// The number of consecutive [`World::prune_empty_storage`] calls during which each table
+    /// was empty, indexed by table id.
+    empty_table_frames: Vec<u32>,
     /// Access cache used by [WorldCell].
     pub(crate) archetype_component_access: ArchetypeComponentAccess,
     main_thread_validator: MainThreadValidator,
-    pub(crate) change_tick: AtomicU32,
-    pub(crate) last_change_tick: u32,
+    pub(crate) change_tick: AtomicTick,
+    pub(crate) last_change_tick: Tick,
+    task_pool: Box<dyn TaskPool>,
 }
 
 impl Default for World {
@@ -93,12 +113,18 @@ impl Default for World {
             storages: Default::default(),
             bundles: Default::default(),
             removed_components: Default::default(),
+            removed_values: Default::default(),
+            removal_generation: 0,
+            index_hooks: Default::default(),
+            empty_archetype_frames: Vec::new(),
+            empty_table_frames: Vec::new(),
             archetype_component_access: Default::default(),
             main_thread_validator: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
-            change_tick: AtomicU32::new(1),
+            change_tick: AtomicTick::new(1),
             last_change_tick: 0,
+            task_pool: task_pool::default_task_pool(),
         }
     }
 }
@@ -133,6 +159,22 @@ impl World {
         &mut self.entities
     }
 
+    /// Retrieves the [`TaskPool`] that runs parallel query iteration, such as
+    /// [`Query::par_for_each`](crate::system::Query::par_for_each).
+    #[inline]
+    pub fn task_pool(&self) -> &dyn TaskPool {
+        &*self.task_pool
+    }
+
+    /// Replaces the [`TaskPool`] that runs parallel query iteration.
+    ///
+    /// By default, worlds use a [`ThreadTaskPool`](crate::task_pool::ThreadTaskPool) with one
+    /// thread per CPU core, or a [`SerialTaskPool`](crate::task_pool::SerialTaskPool) on
+    /// `wasm32`.
+    pub fn set_task_pool(&mut self, task_pool: impl TaskPool + 'static) {
+        self.task_pool = Box::new(task_pool);
+    }
+
     /// Retrieves this world's [Archetypes] collection
     #[inline]
     pub fn archetypes(&self) -> &Archetypes {
@@ -412,6 +454,30 @@ impl World {
         self.get_entity(entity)?.get()
     }
 
+    /// Retrieves a [`Ref`] to the given `entity`'s [Component] of the given type, which can tell
+    /// whether the component was added or changed since the last time this world's systems ran.
+    /// Returns [None] if the `entity` does not have a [Component] of the given type.
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///   x: f32,
+    ///   y: f32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn()
+    ///     .insert(Position { x: 0.0, y: 0.0 })
+    ///     .id();
+    /// let position = world.get_ref::<Position>(entity).unwrap();
+    /// assert!(position.is_added());
+    /// ```
+    #[inline]
+    pub fn get_ref<T: Component>(&self, entity: Entity) -> Option<Ref<'_, T>> {
+        self.get_entity(entity)?.get_ref()
+    }
+
     /// Retrieves a mutable reference to the given `entity`'s [Component] of the given type.
     /// Returns [None] if the `entity` does not have a [Component] of the given type.
     /// ```
@@ -470,6 +536,10 @@ impl World {
         for entities in self.removed_components.values_mut() {
             entities.clear();
         }
+        for buffer in self.removed_values.values_mut() {
+            buffer.clear();
+        }
+        self.removal_generation = self.removal_generation.wrapping_add(1);
 
         self.last_change_tick = self.increment_change_tick();
     }
@@ -1105,26 +1175,112 @@ impl World {
     }
 
     #[inline]
-    pub fn increment_change_tick(&self) -> u32 {
+    pub fn increment_change_tick(&self) -> Tick {
         self.change_tick.fetch_add(1, Ordering::AcqRel)
     }
 
     #[inline]
-    pub fn read_change_tick(&self) -> u32 {
+    pub fn read_change_tick(&self) -> Tick {
         self.change_tick.load(Ordering::Acquire)
     }
 
     #[inline]
-    pub fn change_tick(&mut self) -> u32 {
+    pub fn change_tick(&mut self) -> Tick {
         *self.change_tick.get_mut()
     }
 
     #[inline]
-    pub fn last_change_tick(&self) -> u32 {
+    pub fn last_change_tick(&self) -> Tick {
         self.last_change_tick
     }
 
+    /// Releases the unused capacity of the world's tables, sparse sets, archetypes, resources,
+    /// entity metadata and removed component lists.
+    ///
+    /// This is useful after despawning many entities, e.g. when unloading a level. Storages grow
+    /// again as needed.
+    pub fn shrink_to_fit(&mut self) {
+        self.storages.tables.shrink_to_fit();
+        self.storages.sparse_sets.shrink_to_fit();
+        for archetype in self.archetypes.archetypes.iter_mut() {
+            archetype.shrink_to_fit();
+        }
+        self.entities.shrink_to_fit();
+        for removed in self.removed_components.values_mut() {
+            removed.shrink_to_fit();
+        }
+    }
+
+    /// Removes the archetypes and tables that were empty during the last `frames` calls to this
+    /// method, which should be called once per frame. The empty archetype and the empty table
+    /// are never removed, and a table is only removed once none of its archetypes is left.
+    ///
+    /// Removed archetypes and tables free all of their memory, but keep a placeholder slot so
+    /// that ids stay stable: their ids are never handed out again, and archetypes or tables
+    /// with the same components get new ids. Removing archetypes bumps the
+    /// [`ArchetypeGeneration::removals`](crate::archetype::ArchetypeGeneration::removals) count,
+    /// which makes [`QueryState`]s drop the removed archetypes and tables from their matched
+    /// lists the next time they are updated.
+    pub fn prune_empty_storage(&mut self, frames: u32) {
+        let frames = frames.max(1);
+
+        self.empty_archetype_frames.resize(self.archetypes.len(), 0);
+        let mut removed_archetypes = Vec::new();
+        for (archetype, empty_frames) in self
+            .archetypes
+            .archetypes
+            .iter()
+            .zip(&mut self.empty_archetype_frames)
+        {
+            if archetype.is_removed()
+                || archetype.id() == ArchetypeId::EMPTY
+                || archetype.id() == ArchetypeId::RESOURCE
+            {
+                continue;
+            }
+            if !archetype.is_empty() {
+                *empty_frames = 0;
+                continue;
+            }
+            *empty_frames = empty_frames.saturating_add(1);
+            if *empty_frames >= frames {
+                removed_archetypes.push(archetype.id());
+            }
+        }
+        self.archetypes.remove(&removed_archetypes);
+
+        let tables = &mut self.storages.tables;
+        let mut used_tables = FixedBitSet::with_capacity(tables.len());
+        for archetype in self.archetypes.iter() {
+            used_tables.insert(archetype.table_id().index());
+        }
+        self.empty_table_frames.resize(tables.len(), 0);
+        for (index, empty_frames) in self.empty_table_frames.iter_mut().enumerate() {
+            let table_id = TableId::new(index);
+            let is_empty = match tables.get(table_id) {
+                Some(table) => table.is_empty(),
+                None => continue,
+            };
+            if table_id == TableId::empty() || !is_empty {
+                *empty_frames = 0;
+                continue;
+            }
+            *empty_frames = empty_frames.saturating_add(1);
+            if *empty_frames >= frames && !used_tables.contains(index) {
+                tables.remove(table_id);
+            }
+        }
+    }
+
+    /// Clamps the age of all component change ticks, so that they are still detected correctly
+    /// after the world's change tick wraps around.
+    ///
+    /// This does nothing with the `u64_ticks` feature, as 64-bit ticks can't realistically wrap
+    /// around.
     pub fn check_change_ticks(&mut self) {
+        if cfg!(feature = "u64_ticks") {
+            return;
+        }
         // Iterate over all component change ticks, clamping their age to max age
         // PERF: parallelize
         let change_tick = self.change_tick();
@@ -1137,10 +1293,37 @@ impl World {
     }
 
     pub fn clear_entities(&mut self) {
+        self.drop_tag_components();
         self.storages.tables.clear();
         self.storages.sparse_sets.clear();
         self.archetypes.clear_entities();
         self.entities.clear();
+        let index_hooks = self.index_hooks.values().copied().collect::<Vec<_>>();
+        for hooks in index_hooks {
+            (hooks.clear)(self);
+        }
+    }
+
+    /// Drops the tag components of every entity. Tags are not stored anywhere, so unlike other
+    /// components they are not dropped along with the storages.
+    fn drop_tag_components(&mut self) {
+        for archetype in self.archetypes.iter() {
+            for component_id in archetype.tag_components() {
+                // SAFE: component_id exists, and each entity of the archetype has one such tag
+                unsafe {
+                    let component_info = self.components.get_info_unchecked(*component_id);
+                    for _ in 0..archetype.len() {
+                        (component_info.drop())(component_info.tag_ptr());
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for World {
+    fn drop(&mut self) {
+        self.drop_tag_components();
     }
 }
 
@@ -0,0 +1,125 @@
+use crate::{
+    component::{Component, ComponentId, Components},
+    entity::Entity,
+    storage::SparseSet,
+    world::World,
+};
+use downcast_rs::{impl_downcast, Downcast};
+
+/// A type-erased buffer of the values of a removed component.
+pub(crate) trait RemovedValueBuffer: Downcast + Send + Sync {
+    /// Moves the value `value` points to into the buffer.
+    ///
+    /// # Safety
+    /// `value` must point to a valid value of the buffer's component type, and the caller must
+    /// forget it afterwards.
+    unsafe fn push(&mut self, entity: Entity, value: *mut u8);
+
+    /// Drops every value in the buffer.
+    fn clear(&mut self);
+}
+
+impl_downcast!(RemovedValueBuffer);
+
+pub(crate) struct RemovedValueVec<T> {
+    pub(crate) values: Vec<(Entity, T)>,
+}
+
+impl<T: Component> RemovedValueBuffer for RemovedValueVec<T> {
+    unsafe fn push(&mut self, entity: Entity, value: *mut u8) {
+        self.values.push((entity, value.cast::<T>().read()));
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// Moves the removed component `value` into its removed value buffer, or drops it if the
+/// component's removed values are not tracked.
+///
+/// # Safety
+/// `value` must point to a valid value of the component `component_id`, and the caller must
+/// forget it afterwards.
+pub(crate) unsafe fn store_or_drop_removed(
+    removed_values: &mut SparseSet<ComponentId, Box<dyn RemovedValueBuffer>>,
+    components: &Components,
+    component_id: ComponentId,
+    entity: Entity,
+    value: *mut u8,
+) {
+    match removed_values.get_mut(component_id) {
+        Some(buffer) => buffer.push(entity, value),
+        None => (components.get_info_unchecked(component_id).drop())(value),
+    }
+}
+
+impl World {
+    /// Keeps the values of removed `T` components, so they can be read with
+    /// [`World::removed_values`] or the [`RemovedValues`](crate::system::RemovedValues) system
+    /// parameter until the next call to [`World::clear_trackers`].
+    ///
+    /// This covers components removed with
+    /// [`EntityMut::remove_bundle_intersection`](crate::world::EntityMut::remove_bundle_intersection)
+    /// or by commands, and the components of despawned entities. Values returned to the caller,
+    /// e.g. by [`EntityMut::remove`](crate::world::EntityMut::remove), are not kept.
+    ///
+    /// Does nothing if the values of `T` are already kept.
+    pub fn track_removed_values<T: Component>(&mut self) {
+        let component_id = self.init_component::<T>();
+        if !self.removed_values.contains(component_id) {
+            self.removed_values.insert(
+                component_id,
+                Box::new(RemovedValueVec::<T> { values: Vec::new() }),
+            );
+        }
+    }
+
+    /// Returns the removed `T` components and the entities they were removed from, since the
+    /// last call to [`World::clear_trackers`].
+    ///
+    /// This is empty unless [`World::track_removed_values`] was called for `T`.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct NetworkId(u64);
+    ///
+    /// let mut world = World::new();
+    /// world.track_removed_values::<NetworkId>();
+    /// let entity = world.spawn().insert(NetworkId(42)).id();
+    /// world.despawn(entity);
+    /// assert_eq!(world.removed_values::<NetworkId>(), &[(entity, NetworkId(42))]);
+    /// ```
+    pub fn removed_values<T: Component>(&self) -> &[(Entity, T)] {
+        match self.components.component_id::<T>() {
+            Some(component_id) => self.removed_values_with_id(component_id),
+            None => &[],
+        }
+    }
+
+    /// Returns the removed values of the component `component_id`, which must be of type `T`.
+    pub(crate) fn removed_values_with_id<T: Component>(
+        &self,
+        component_id: ComponentId,
+    ) -> &[(Entity, T)] {
+        self.removed_values
+            .get(component_id)
+            .and_then(|buffer| buffer.downcast_ref::<RemovedValueVec<T>>())
+            .map_or(&[], |buffer| &buffer.values[..])
+    }
+
+    /// Moves `value` into the removed value buffer of `T`, or drops it if the removed values of
+    /// `T` are not tracked.
+    pub(crate) fn store_removed_value<T: Component>(&mut self, entity: Entity, value: T) {
+        let buffer = self
+            .components
+            .component_id::<T>()
+            .and_then(|component_id| self.removed_values.get_mut(component_id))
+            .and_then(|buffer| buffer.downcast_mut::<RemovedValueVec<T>>());
+        if let Some(buffer) = buffer {
+            buffer.values.push((entity, value));
+        }
+    }
+}